@@ -4,7 +4,8 @@ const STACK_TOP_ADDR: usize = 14752;
 
 use linked_list_allocator::LockedHeap;
 
-#[global_allocator]
+// only on the console; native builds (e.g. unit tests) use the system allocator.
+#[cfg_attr(target_arch = "wasm32", global_allocator)]
 static ALLOCATOR: LockedHeap = LockedHeap::empty();
 
 pub fn init_heap() {
@@ -10,4 +10,19 @@ pub mod music;
 pub mod menus;
 pub mod popup_text;
 pub mod ability_cards;
-pub mod cloud;
\ No newline at end of file
+pub mod pickups;
+pub mod effects;
+pub mod fixed;
+pub mod step;
+pub mod minimap;
+pub mod camera_effects;
+pub mod particles;
+pub mod sfx;
+// only for rendering audio off to files in tests, outside of the cart
+#[cfg(test)]
+pub mod synth;
+pub mod player_stats;
+pub mod versus;
+pub mod save;
+pub mod daily;
+pub mod endless;
\ No newline at end of file
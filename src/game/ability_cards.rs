@@ -1,20 +1,28 @@
 use crate::spritesheet::{PresetSprites, Sprite};
 
-#[derive(PartialEq)]
+use super::{effects::EffectTypes, fixed::Fixed};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum AbilityCardTypes {
     Kitty,
     Piggy,
     Lizard,
     Bird,
+    Dash,
+    DoubleJump,
+    Magnet,
+    Reveal,
+    FreezeTimer,
+    Shrink,
 }
 
 pub struct AbilityCard {
     pub card_type: AbilityCardTypes,
     pub sprite: &'static Sprite,
-    pub target_x: f32,
-    pub target_y: f32,
-    pub x_pos: f32,
-    pub y_pos: f32,
+    pub target_x: Fixed,
+    pub target_y: Fixed,
+    pub x_pos: Fixed,
+    pub y_pos: Fixed,
 }
 
 pub const N_CARDS: usize = 5;
@@ -30,24 +38,99 @@ impl AbilityCard {
             AbilityCardTypes::Piggy => PresetSprites::PiggyCard,
             AbilityCardTypes::Lizard => PresetSprites::LizardCard,
             AbilityCardTypes::Bird => PresetSprites::BirdCard,
+            AbilityCardTypes::Dash => PresetSprites::DashCard,
+            AbilityCardTypes::DoubleJump => PresetSprites::DoubleJumpCard,
+            AbilityCardTypes::Magnet => PresetSprites::MagnetCard,
+            AbilityCardTypes::Reveal => PresetSprites::RevealCard,
+            AbilityCardTypes::FreezeTimer => PresetSprites::FreezeCard,
+            AbilityCardTypes::Shrink => PresetSprites::ShrinkCard,
         };
 
         AbilityCard {
             card_type: card,
             sprite: Sprite::from_preset(&preset_sprite_type),
-            target_x: 0.0,
-            target_y: 0.0,
-            x_pos,
-            y_pos,
+            target_x: Fixed::ZERO,
+            target_y: Fixed::ZERO,
+            x_pos: Fixed::from_int(x_pos as i32),
+            y_pos: Fixed::from_int(y_pos as i32),
         }
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum AbilityCardUsageResult {
     NothingHappened,
     GainedTime(u32),
-    EnabledFlyAndTime(u32),
-    EnabledWarpAndTime(u32),
+    // the effect, how many frames it lasts, and seconds of time gained along with it.
+    EnabledTimedEffect(EffectTypes, u16, u32),
+    WarpToNearestKitty,
+}
+
+// A mix of cards that does something special when they're on top of the stack, in this order.
+pub struct ComboRecipe {
+    pub name: &'static str,
+    // oldest card first, so the last card here is the one on top of the stack.
+    pub cards: &'static [AbilityCardTypes],
+    pub result: AbilityCardUsageResult,
+}
+
+pub const COMBO_RECIPES: [ComboRecipe; 4] = [
+    ComboRecipe {
+        name: "kitty warp",
+        cards: &[AbilityCardTypes::Bird, AbilityCardTypes::Lizard],
+        result: AbilityCardUsageResult::WarpToNearestKitty,
+    },
+    ComboRecipe {
+        name: "piggy bank",
+        cards: &[AbilityCardTypes::Piggy, AbilityCardTypes::Piggy, AbilityCardTypes::Kitty],
+        result: AbilityCardUsageResult::GainedTime(40),
+    },
+    ComboRecipe {
+        name: "rocket",
+        cards: &[AbilityCardTypes::Dash, AbilityCardTypes::DoubleJump],
+        result: AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Fly, 10 * 60, 0),
+    },
+    ComboRecipe {
+        name: "time out",
+        cards: &[AbilityCardTypes::FreezeTimer, AbilityCardTypes::Piggy],
+        result: AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::FreezeTimer, 8 * 60, 10),
+    },
+];
+
+// the longest recipe matching the top of the stack (the end of card_types), if any.
+pub fn find_combo(card_types: &[AbilityCardTypes]) -> Option<&'static ComboRecipe> {
+    find_combo_in(&COMBO_RECIPES, card_types)
+}
+
+fn find_combo_in<'a>(recipes: &'a [ComboRecipe], card_types: &[AbilityCardTypes]) -> Option<&'a ComboRecipe> {
+    let mut best: Option<&'a ComboRecipe> = None;
+    for recipe in recipes.iter() {
+        if card_types.ends_with(recipe.cards) {
+            match best {
+                Some(b) if b.cards.len() >= recipe.cards.len() => {}
+                _ => best = Some(recipe),
+            }
+        }
+    }
+    best
+}
+
+impl AbilityCardTypes {
+    // what a card pack can contain. The newer cards only come from packs.
+    pub fn random_from_pack(roll: u64) -> AbilityCardTypes {
+        match roll % 10 {
+            0 => AbilityCardTypes::Kitty,
+            1 => AbilityCardTypes::Piggy,
+            2 => AbilityCardTypes::Lizard,
+            3 => AbilityCardTypes::Bird,
+            4 => AbilityCardTypes::Dash,
+            5 => AbilityCardTypes::DoubleJump,
+            6 => AbilityCardTypes::Magnet,
+            7 => AbilityCardTypes::Reveal,
+            8 => AbilityCardTypes::FreezeTimer,
+            _ => AbilityCardTypes::Shrink,
+        }
+    }
 }
 
 impl AbilityCardStack {
@@ -57,11 +140,21 @@ impl AbilityCardStack {
         }
     }
 
+    // the types of the cards in the stack, bottom to top.
+    fn card_types(self: &Self) -> Vec<AbilityCardTypes> {
+        self.cards.iter().flatten().map(|c| c.card_type).collect()
+    }
+
+    // the combo the top cards would make if they were used now.
+    pub fn current_combo(self: &Self) -> Option<&'static ComboRecipe> {
+        find_combo(&self.card_types())
+    }
+
     pub fn move_cards(self: &mut Self) {
         for card in &mut self.cards.iter_mut() {
             match card {
                 Some(c) => {
-                    const CARD_PID_P: f32 = 0.125;
+                    const CARD_PID_P: Fixed = Fixed::from_ratio(1, 8);
                     c.x_pos += CARD_PID_P * (c.target_x - c.x_pos);
                     c.y_pos += CARD_PID_P * (c.target_y - c.y_pos);
                 },
@@ -75,6 +168,17 @@ impl AbilityCardStack {
         if self.cards.is_empty() {
             return AbilityCardUsageResult::NothingHappened;
         }
+
+        // combos take priority over runs of the same card
+        match self.current_combo() {
+            Some(recipe) => {
+                for _ in 0..recipe.cards.len() {
+                    self.cards.pop();
+                }
+                return recipe.result;
+            }
+            None => {}
+        }
         match &self.cards[self.cards.len() - 1] {
             None => {}
             Some(card) => {
@@ -106,10 +210,15 @@ impl AbilityCardStack {
                     AbilityCardTypes::Piggy => {
                         AbilityCardUsageResult::GainedTime(n_consumed * 10)
                     },
-                    AbilityCardTypes::Lizard => {
-                        AbilityCardUsageResult::EnabledWarpAndTime(n_consumed * 3)
-                    },
-                    AbilityCardTypes::Bird => AbilityCardUsageResult::EnabledFlyAndTime(n_consumed * 3),
+                    // timed effects: seconds per card, in frames
+                    AbilityCardTypes::Lizard => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Warp, n_consumed as u16 * 15 * 60, n_consumed * 3),
+                    AbilityCardTypes::Bird => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Fly, n_consumed as u16 * 8 * 60, n_consumed * 3),
+                    AbilityCardTypes::Dash => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Dash, n_consumed as u16 * 3 * 60, 0),
+                    AbilityCardTypes::DoubleJump => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::DoubleJump, n_consumed as u16 * 8 * 60, 0),
+                    AbilityCardTypes::Magnet => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Magnet, n_consumed as u16 * 6 * 60, 0),
+                    AbilityCardTypes::Reveal => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Reveal, n_consumed as u16 * 6 * 60, 0),
+                    AbilityCardTypes::FreezeTimer => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::FreezeTimer, n_consumed as u16 * 3 * 60, 0),
+                    AbilityCardTypes::Shrink => AbilityCardUsageResult::EnabledTimedEffect(EffectTypes::Shrink, n_consumed as u16 * 5 * 60, 0),
                 };
 
                 // remove cards off the end (to ensure correct ordering)
@@ -125,3 +234,62 @@ impl AbilityCardStack {
         AbilityCardUsageResult::NothingHappened
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use AbilityCardTypes::*;
+
+    #[test]
+    fn combo_matches_top_of_stack_in_order() {
+        assert_eq!(find_combo(&[Kitty, Bird, Lizard]).map(|r| r.name), Some("kitty warp"));
+        assert_eq!(find_combo(&[Lizard, Bird]).map(|r| r.name), None);
+        assert_eq!(find_combo(&[Bird, Lizard, Kitty]).map(|r| r.name), None);
+    }
+
+    #[test]
+    fn combo_needs_every_card() {
+        assert_eq!(find_combo(&[Piggy, Kitty]).map(|r| r.name), None);
+        assert_eq!(
+            find_combo(&[Piggy, Piggy, Kitty]).map(|r| r.result),
+            Some(AbilityCardUsageResult::GainedTime(40))
+        );
+    }
+
+    #[test]
+    fn no_combo_for_empty_or_same_cards() {
+        assert!(find_combo(&[]).is_none());
+        assert!(find_combo(&[Kitty, Kitty, Kitty]).is_none());
+        assert!(find_combo(&[Bird, Bird]).is_none());
+    }
+
+    #[test]
+    fn longest_recipe_wins() {
+        // the short recipe is also the top of the long one, whichever way round they're listed
+        let short = || ComboRecipe { name: "short", cards: &[Bird, Lizard], result: AbilityCardUsageResult::GainedTime(1) };
+        let long = || ComboRecipe { name: "long", cards: &[Kitty, Bird, Lizard], result: AbilityCardUsageResult::GainedTime(2) };
+        let short_first = [short(), long()];
+        assert_eq!(find_combo_in(&short_first, &[Piggy, Kitty, Bird, Lizard]).map(|r| r.name), Some("long"));
+        assert_eq!(find_combo_in(&short_first, &[Piggy, Bird, Lizard]).map(|r| r.name), Some("short"));
+        let long_first = [long(), short()];
+        assert_eq!(find_combo_in(&long_first, &[Kitty, Bird, Lizard]).map(|r| r.name), Some("long"));
+    }
+
+    #[test]
+    fn using_a_combo_takes_its_cards_off_the_top() {
+        crate::spritesheet::Sprite::init_all_sprites_for_tests();
+        let mut stack = AbilityCardStack { cards: Vec::new() };
+        for card in [Bird, Piggy, Piggy, Kitty] {
+            stack.try_push_card(card, 0.0, 0.0);
+        }
+        // the whole recipe gets used up, rather than just the kitty on top
+        assert_eq!(stack.try_use_cards(), AbilityCardUsageResult::GainedTime(40));
+        assert_eq!(stack.card_types(), vec![Bird]);
+
+        for card in [Kitty, Bird, Lizard] {
+            stack.try_push_card(card, 0.0, 0.0);
+        }
+        assert_eq!(stack.try_use_cards(), AbilityCardUsageResult::WarpToNearestKitty);
+        assert_eq!(stack.card_types(), vec![Bird, Kitty]);
+    }
+}
@@ -1,3 +1,70 @@
+use super::{
+    game_constants::{SCREEN_HEIGHT_PX, SCREEN_WIDTH_PX, TILE_HEIGHT_PX, TILE_WIDTH_PX},
+    game_map::GameMap,
+};
+
+// what the camera is allowed to show.
+#[derive(Clone, Copy)]
+pub enum CameraBounds {
+    Unbounded,
+    // stay inside the outer edges of the whole map
+    Map,
+    // stay inside the chunk the target is in
+    Chunk,
+}
+
+// how the camera follows its target.
+#[derive(Clone, Copy)]
+pub struct CameraSettings {
+    // how far ahead of the target to look, in the direction it's facing
+    pub look_ahead_px: f32,
+    // how much of the way the look-ahead swings over each frame, after turning around
+    pub look_ahead_ease: f32,
+    // the target can go this far above or below the center of the view before the view follows
+    pub vertical_dead_zone_px: f32,
+    pub bounds: CameraBounds,
+    // how much of the way to the target the view moves each frame
+    pub slew_gain: f32,
+}
+
+impl CameraSettings {
+    // a plain follow, like the camera always did.
+    pub const CLASSIC: CameraSettings = CameraSettings {
+        look_ahead_px: 0.0,
+        look_ahead_ease: 1.0,
+        vertical_dead_zone_px: 0.0,
+        bounds: CameraBounds::Unbounded,
+        slew_gain: 0.3,
+    };
+
+    pub const DEFAULT: CameraSettings = CameraSettings {
+        look_ahead_px: 24.0,
+        look_ahead_ease: 0.05,
+        vertical_dead_zone_px: 12.0,
+        bounds: CameraBounds::Map,
+        slew_gain: 0.3,
+    };
+}
+
+// the ways of following that can be picked on the select screen.
+#[derive(Clone, Copy)]
+pub enum CameraStyles {
+    // looks ahead, and stays inside the map
+    Smart,
+    // looks ahead, and stays inside the room the player is in
+    Room,
+    Classic,
+}
+
+impl CameraStyles {
+    pub fn settings(self: &Self) -> CameraSettings {
+        match self {
+            CameraStyles::Smart => CameraSettings::DEFAULT,
+            CameraStyles::Room => CameraSettings { bounds: CameraBounds::Chunk, ..CameraSettings::DEFAULT },
+            CameraStyles::Classic => CameraSettings::CLASSIC,
+        }
+    }
+}
 
 #[derive(Clone, Copy)]
 
@@ -7,20 +74,275 @@ pub struct Camera {
 
     pub current_viewing_x_target: f32,
     pub current_viewing_y_target: f32,
+
+    pub settings: CameraSettings,
+    // the look-ahead right now, as it eases over to the side the target faces
+    pub look_ahead_x: f32,
+    // the height the view centers on. Only moves when the target leaves the dead zone.
+    pub focus_y: f32,
+    // screen shake, on top of where the view would otherwise be
+    pub shake_x: f32,
+    pub shake_y: f32,
+    // where on the screen this camera's view goes, for split-screen
+    pub view_x: i32,
+    pub view_y: i32,
+    pub view_width: u32,
+    pub view_height: u32,
 }
 
 impl Camera {
+    pub fn new(settings: CameraSettings) -> Camera {
+        Camera {
+            current_viewing_x_offset: 0.0,
+            current_viewing_y_offset: 0.0,
+            current_viewing_x_target: 0.0,
+            current_viewing_y_target: 0.0,
+            settings,
+            look_ahead_x: 0.0,
+            focus_y: 0.0,
+            shake_x: 0.0,
+            shake_y: 0.0,
+            view_x: 0,
+            view_y: 0,
+            view_width: SCREEN_WIDTH_PX as u32,
+            view_height: SCREEN_HEIGHT_PX as u32,
+        }
+    }
+
+    // where view number view_i goes when the screen is split n_views ways: (x, y, width, height).
+    // Two views go one over the other, and three or four get a corner each.
+    pub fn split_viewport(n_views: usize, view_i: usize) -> (i32, i32, u32, u32) {
+        const HALF_W: u32 = SCREEN_WIDTH_PX as u32 / 2;
+        const HALF_H: u32 = SCREEN_HEIGHT_PX as u32 / 2;
+        match n_views {
+            0 | 1 => (0, 0, SCREEN_WIDTH_PX as u32, SCREEN_HEIGHT_PX as u32),
+            2 => (0, (HALF_H * view_i as u32) as i32, SCREEN_WIDTH_PX as u32, HALF_H),
+            _ => ((HALF_W * (view_i % 2) as u32) as i32, (HALF_H * (view_i / 2) as u32) as i32, HALF_W, HALF_H),
+        }
+    }
+
+    pub fn set_viewport(self: &mut Self, (x, y, width, height): (i32, i32, u32, u32)) {
+        self.view_x = x;
+        self.view_y = y;
+        self.view_width = width;
+        self.view_height = height;
+    }
+
+    // aim at the point (the middle of whatever we're following), then slew over.
+    pub fn follow(self: &mut Self, x: f32, y: f32, is_facing_right: bool, map: &GameMap) {
+        let settings = self.settings;
+
+        // LOOK AHEAD
+        let look_ahead_target = match is_facing_right {
+            true => settings.look_ahead_px,
+            false => -settings.look_ahead_px,
+        };
+        self.look_ahead_x += settings.look_ahead_ease * (look_ahead_target - self.look_ahead_x);
+
+        // VERTICAL DEAD ZONE
+        if y > self.focus_y + settings.vertical_dead_zone_px {
+            self.focus_y = y - settings.vertical_dead_zone_px;
+        } else if y < self.focus_y - settings.vertical_dead_zone_px {
+            self.focus_y = y + settings.vertical_dead_zone_px;
+        }
+
+        self.current_viewing_x_target = x + self.look_ahead_x - self.view_width as f32 / 2.0;
+        self.current_viewing_y_target = self.focus_y - self.view_height as f32 / 2.0;
+
+        // CLAMP TO THE ROOM
+        match Camera::bounds_around(settings.bounds, x, y, map) {
+            Some((min_x, min_y, max_x, max_y)) => {
+                fn clamp_axis(target: f32, min: f32, max: f32, view: f32) -> f32 {
+                    // too small to fill the view, so center it
+                    if max - min <= view {
+                        return (min + max - view) / 2.0;
+                    }
+                    num::clamp(target, min, max - view)
+                }
+                self.current_viewing_x_target = clamp_axis(self.current_viewing_x_target, min_x, max_x, self.view_width as f32);
+                self.current_viewing_y_target = clamp_axis(self.current_viewing_y_target, min_y, max_y, self.view_height as f32);
+            }
+            None => {}
+        }
+
+        self.slew();
+    }
+
+    // the area the view has to stay inside (min x, min y, max x, max y), in pixels.
+    fn bounds_around(bounds: CameraBounds, x: f32, y: f32, map: &GameMap) -> Option<(f32, f32, f32, f32)> {
+        let mut found = None;
+        for chunk in map.chunks.iter() {
+            let min_x = (chunk.bound.x * TILE_WIDTH_PX as i32) as f32;
+            let min_y = (chunk.bound.y * TILE_HEIGHT_PX as i32) as f32;
+            let max_x = min_x + (chunk.bound.width * TILE_WIDTH_PX) as f32;
+            let max_y = min_y + (chunk.bound.height * TILE_HEIGHT_PX) as f32;
+            match bounds {
+                CameraBounds::Unbounded => return None,
+                CameraBounds::Chunk => {
+                    if x >= min_x && x < max_x && y >= min_y && y < max_y {
+                        return Some((min_x, min_y, max_x, max_y));
+                    }
+                }
+                CameraBounds::Map => {}
+            }
+            found = match found {
+                Some((a, b, c, d)) => Some((min_x.min(a), min_y.min(b), max_x.max(c), max_y.max(d))),
+                None => Some((min_x, min_y, max_x, max_y)),
+            };
+        }
+        // outside of every chunk, so fall back to the whole map
+        found
+    }
+
+    // the shake only moves what gets drawn, not where the camera is.
+    pub fn set_shake(self: &mut Self, x: f32, y: f32) {
+        self.shake_x = x;
+        self.shake_y = y;
+    }
+
     pub fn slew(self: &mut Self) {
-        // #TODO project across center so it leads target
         let x_err = self.current_viewing_x_target - self.current_viewing_x_offset;
         let y_err = self.current_viewing_y_target - self.current_viewing_y_offset;
 
-        const KP: f32 = 0.3;
-        self.current_viewing_x_offset += KP * x_err;
-        self.current_viewing_y_offset += KP * y_err;
+        self.current_viewing_x_offset += self.settings.slew_gain * x_err;
+        self.current_viewing_y_offset += self.settings.slew_gain * y_err;
     }
 
-    pub fn cvt_world_to_screen_coords(self: &Self, x_pos: f32, y_pos: f32) -> (f32, f32) {
+    // where something is inside this camera's view, without the shake. The simulation goes by this.
+    pub fn cvt_world_to_view_coords(self: &Self, x_pos: f32, y_pos: f32) -> (f32, f32) {
         (x_pos - self.current_viewing_x_offset, y_pos - self.current_viewing_y_offset)
     }
-}
\ No newline at end of file
+
+    pub fn cvt_world_to_screen_coords(self: &Self, x_pos: f32, y_pos: f32) -> (f32, f32) {
+        let (x, y) = self.cvt_world_to_view_coords(x_pos, y_pos);
+        (x - self.shake_x + self.view_x as f32, y - self.shake_y + self.view_y as f32)
+    }
+
+    // the part of something at (x, y) on the screen, this big, that lands inside this view,
+    // as (x, y, width, height). None if it's all outside.
+    pub fn clip(self: &Self, x: i32, y: i32, width: u32, height: u32) -> Option<(i32, i32, u32, u32)> {
+        let left = x.max(self.view_x);
+        let top = y.max(self.view_y);
+        let right = (x + width as i32).min(self.view_x + self.view_width as i32);
+        let bottom = (y + height as i32).min(self.view_y + self.view_height as i32);
+        if right <= left || bottom <= top {
+            return None;
+        }
+        Some((left, top, (right - left) as u32, (bottom - top) as u32))
+    }
+
+    // whether text at (x, y) on the screen, this big, should get drawn in this view.
+    // Text can't be clipped, so it can hang off the edges of the screen,
+    // but has to fit inside wherever the view borders another view.
+    pub fn can_draw(self: &Self, x: i32, y: i32, width: u32, height: u32) -> bool {
+        fn fits_axis(pos: i32, size: i32, view_pos: i32, view_size: i32, screen_size: i32) -> bool {
+            let after_start = match view_pos {
+                0 => pos + size > 0,
+                _ => pos >= view_pos,
+            };
+            let before_end = match view_pos + view_size >= screen_size {
+                true => pos < screen_size,
+                false => pos + size <= view_pos + view_size,
+            };
+            after_start && before_end
+        }
+        fits_axis(x, width as i32, self.view_x, self.view_width as i32, SCREEN_WIDTH_PX as i32)
+            && fits_axis(y, height as i32, self.view_y, self.view_height as i32, SCREEN_HEIGHT_PX as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::mapchunk::{MapChunk, TileAlignedBoundingBox};
+
+    // two chunks side by side, 300x200 px together.
+    fn two_room_map() -> GameMap {
+        let mut map = GameMap::create_map();
+        for (x, w) in [(0, 40), (40, 20)] {
+            let mut chunk = MapChunk::init();
+            chunk.bound = TileAlignedBoundingBox::init(x, 0, w, 40);
+            chunk.initialize();
+            map.add_chunk(chunk);
+        }
+        map
+    }
+
+    fn settled(camera: &mut Camera, x: f32, y: f32, is_facing_right: bool, map: &GameMap) {
+        for _ in 0..200 {
+            camera.follow(x, y, is_facing_right, map);
+        }
+    }
+
+    #[test]
+    fn looks_ahead_the_way_the_target_faces() {
+        let map = two_room_map();
+        let mut camera = Camera::new(CameraSettings { bounds: CameraBounds::Unbounded, ..CameraSettings::DEFAULT });
+        settled(&mut camera, 150.0, 100.0, true, &map);
+        let facing_right = camera.current_viewing_x_offset;
+        settled(&mut camera, 150.0, 100.0, false, &map);
+        assert!(facing_right - camera.current_viewing_x_offset > 40.0);
+    }
+
+    #[test]
+    fn small_hops_stay_in_the_dead_zone() {
+        let map = two_room_map();
+        let mut camera = Camera::new(CameraSettings { bounds: CameraBounds::Unbounded, ..CameraSettings::DEFAULT });
+        settled(&mut camera, 150.0, 100.0, true, &map);
+        let resting_y = camera.current_viewing_y_offset;
+        for hop_y in [96.0, 92.0, 90.0, 92.0, 96.0, 100.0] {
+            camera.follow(150.0, hop_y, true, &map);
+            assert_eq!(camera.current_viewing_y_offset, resting_y);
+        }
+        // a big jump moves the view
+        settled(&mut camera, 150.0, 40.0, true, &map);
+        assert!(camera.current_viewing_y_offset < resting_y);
+    }
+
+    #[test]
+    fn view_stays_inside_the_map_and_chunk() {
+        let map = two_room_map();
+        let mut camera = Camera::new(CameraSettings::DEFAULT);
+        settled(&mut camera, 5.0, 5.0, false, &map);
+        assert!(camera.current_viewing_x_offset > -0.5 && camera.current_viewing_y_offset > -0.5);
+        settled(&mut camera, 295.0, 195.0, true, &map);
+        assert!(camera.current_viewing_x_offset + SCREEN_WIDTH_PX as f32 <= 300.5);
+        assert!(camera.current_viewing_y_offset + SCREEN_HEIGHT_PX as f32 <= 200.5);
+
+        // the right chunk is only 100 px wide, so it gets centered
+        camera.settings.bounds = CameraBounds::Chunk;
+        settled(&mut camera, 250.0, 100.0, true, &map);
+        assert!((camera.current_viewing_x_offset - (250.0 - SCREEN_WIDTH_PX as f32 / 2.0)).abs() < 0.5);
+    }
+
+    #[test]
+    fn split_views_only_draw_inside_themselves() {
+        let map = two_room_map();
+        let mut camera = Camera::new(CameraSettings { bounds: CameraBounds::Unbounded, ..CameraSettings::DEFAULT });
+        // the top right corner of four
+        camera.set_viewport(Camera::split_viewport(4, 1));
+        assert_eq!((camera.view_x, camera.view_y, camera.view_width, camera.view_height), (80, 0, 80, 80));
+
+        // the target ends up in the middle of the view, not the screen
+        settled(&mut camera, 150.0, 100.0, true, &map);
+        let (sx, sy) = camera.cvt_world_to_screen_coords(150.0 + camera.look_ahead_x, camera.focus_y);
+        assert!((sx - 120.0).abs() < 0.5 && (sy - 40.0).abs() < 0.5);
+
+        // hanging off the screen edge is fine, but not over into the views next door
+        assert!(camera.can_draw(155, -3, 8, 8));
+        assert!(camera.can_draw(80, 72, 8, 8));
+        assert!(!camera.can_draw(77, 10, 8, 8));
+        assert!(!camera.can_draw(100, 75, 8, 8));
+
+        // a whole-screen view draws anything touching the screen
+        let full = Camera::new(CameraSettings::DEFAULT);
+        assert!(full.can_draw(-4, 155, 8, 8));
+        assert!(!full.can_draw(-8, 10, 8, 8));
+
+        // sprites crossing into the next view get cut at the seam
+        assert_eq!(camera.clip(77, 10, 8, 8), Some((80, 10, 5, 8)));
+        assert_eq!(camera.clip(100, 75, 8, 8), Some((100, 75, 8, 5)));
+        assert_eq!(camera.clip(155, -3, 8, 8), Some((155, 0, 5, 5)));
+        assert_eq!(camera.clip(72, 10, 8, 8), None);
+    }
+}
@@ -0,0 +1,99 @@
+// Screen shake, freeze frames and palette flashes, layered on top of the camera.
+// Gameplay triggers these, and they wear off a bit every step.
+pub struct CameraEffects {
+    // 0 to 1. The shake grows with the square of this, so small bumps barely show.
+    pub trauma: f32,
+    // frames left that gameplay holds still for (hit-stop)
+    pub freeze_frames: u8,
+    // frames left that the background flashes to a palette colour, and which one
+    pub flash_frames: u8,
+    pub flash_color_i: usize,
+    // how far the view gets pushed this frame
+    pub shake_x: f32,
+    pub shake_y: f32,
+    pub t: u32,
+}
+
+impl CameraEffects {
+    pub fn new() -> CameraEffects {
+        CameraEffects {
+            trauma: 0.0,
+            freeze_frames: 0,
+            flash_frames: 0,
+            flash_color_i: 0,
+            shake_x: 0.0,
+            shake_y: 0.0,
+            t: 0,
+        }
+    }
+
+    pub fn add_trauma(self: &mut Self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+
+    pub fn freeze(self: &mut Self, frames: u8) {
+        self.freeze_frames = self.freeze_frames.max(frames);
+    }
+
+    pub fn flash(self: &mut Self, color_i: usize, frames: u8) {
+        self.flash_color_i = color_i;
+        self.flash_frames = self.flash_frames.max(frames);
+    }
+
+    pub fn tick(self: &mut Self) {
+        const TRAUMA_DECAY: f32 = 0.03;
+        const MAX_SHAKE_PX: f32 = 6.0;
+
+        self.t = self.t.wrapping_add(1);
+        self.freeze_frames = self.freeze_frames.saturating_sub(1);
+        self.flash_frames = self.flash_frames.saturating_sub(1);
+        self.trauma = (self.trauma - TRAUMA_DECAY).max(0.0);
+
+        // two waves that don't line up make a jittery (but repeatable) wobble
+        let shake = MAX_SHAKE_PX * self.trauma * self.trauma;
+        let t = self.t as f32;
+        self.shake_x = shake * num::Float::sin(t * 1.9);
+        self.shake_y = shake * num::Float::sin(t * 2.7 + 1.0);
+    }
+
+    // the palette to draw with, with the background swapped out while flashing.
+    pub fn flashed_palette(self: &Self, palette: [u32; 4]) -> [u32; 4] {
+        if self.flash_frames == 0 {
+            return palette;
+        }
+        let mut flashed = palette;
+        flashed[0] = palette[self.flash_color_i];
+        flashed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effects_wear_off() {
+        let mut effects = CameraEffects::new();
+        effects.add_trauma(0.8);
+        effects.add_trauma(0.8);
+        assert_eq!(effects.trauma, 1.0);
+        effects.freeze(4);
+        effects.freeze(2);
+        effects.flash(3, 10);
+
+        let mut frozen_frames = 0;
+        let mut biggest_shake: f32 = 0.0;
+        for _ in 0..60 {
+            if effects.freeze_frames > 0 {
+                frozen_frames += 1;
+            }
+            effects.tick();
+            biggest_shake = biggest_shake.max(effects.shake_x.abs()).max(effects.shake_y.abs());
+        }
+        assert_eq!(frozen_frames, 4);
+        assert!(biggest_shake > 3.0);
+        assert_eq!((effects.trauma, effects.shake_x, effects.shake_y), (0.0, 0.0, 0.0));
+        let palette = [1, 2, 3, 4];
+        assert_eq!(effects.flashed_palette(palette), palette);
+    }
+}
@@ -1,6 +1,6 @@
 
 use crate::{
-    game::{ability_cards::{AbilityCardStack, AbilityCardTypes}, entities::{Player, WarpAbility, WarpState}, popup_text::{PopTextRingbuffer, PopupIcon}},
+    game::{ability_cards::{AbilityCardTypes, N_CARDS}, entities::{Player, WarpAbility, WarpState}, pickups::{Pickup, PickupTypes, CARD_PACK_SIZE, CLOCK_PICKUP_TIME, MAX_SCORE_MULTIPLIER}, popup_text::{PopTextRingbuffer, PopupIcon}},
     spritesheet,
 };
 
@@ -9,9 +9,9 @@ use super::{
     game_constants::{
         TILE_HEIGHT_PX, TILE_WIDTH_PX
     },
-    game_map::GameMap,
-    game_state::GameState,
-    mapchunk::{MapChunk, TileAlignedBoundingBox}, cloud::Cloud,
+    game_map::{GameMap, LockedDoor, WarpPad},
+    game_state::{GameState, RunType},
+    mapchunk::{MapChunk, TileAlignedBoundingBox}, particles::{Emitter, ParticlePool}, sfx::{Sfx, SfxPlayer}, fixed::Fixed,
 };
 
 use crate::wasm4::*;
@@ -101,8 +101,8 @@ pub fn check_absolute_bounding_box_partially_inside_another(
 pub fn get_bound_of_character(character: &Character) -> AbsoluteBoundingBox<i32, u32> {
     let char_positioning = character.sprite.frames[character.current_sprite_i as usize];
     AbsoluteBoundingBox {
-        x: character.x_pos as i32,
-        y: character.y_pos as i32,
+        x: character.x_pos.to_int(),
+        y: character.y_pos.to_int(),
         width: char_positioning.width as u32,
         height: char_positioning.height as u32,
     }
@@ -141,14 +141,15 @@ pub fn check_entity_collisions(game_state: &mut GameState) {
         }
     }
 
+    let versus = matches!(game_state.settings.run_type, RunType::Versus(_));
     for (hit_p_i, hit_npc_i) in &npc_hitlist[..hitlist_i as usize] {
         let opt_p = &mut game_state.players[*hit_p_i as usize];
 
         if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
             let npc = &mut game_state.npcs[*hit_npc_i as usize];
 
-            let pop_x = npc.x_pos;
-            let pop_y = npc.y_pos;
+            let pop_x = npc.x_pos.to_f32();
+            let pop_y = npc.y_pos.to_f32();
 
             match npc.following_i {
                 None => {
@@ -169,25 +170,27 @@ pub fn check_entity_collisions(game_state: &mut GameState) {
                         _ => AbilityCardTypes::Kitty,
                     };
 
-                    // spawn some clouds
-                    for dir in [(1.0, 0.0), (0.5, 0.86), (-0.5, 0.86), (-1.0, 0.0), (-0.5, -0.86), (0.5, -0.86)] {
-                        const CARD_CLOUD_SPEED: f32 = 4.0;
+                    // spawn some clouds and sparkles
+                    game_state.particles.emit(Emitter::CloudBurst, pop_x + 2.0, pop_y + 3.0);
+                    game_state.particles.emit(Emitter::Sparkles, pop_x + 2.0, pop_y + 3.0);
+                    game_state.sfx.trigger(Sfx::KittyFound);
+                    p.stats.kitties_found += 1;
 
-                        let vx = CARD_CLOUD_SPEED * dir.0;
-                        let vy = CARD_CLOUD_SPEED * dir.1;
-                        Cloud::try_push_cloud(&mut game_state.clouds, npc.x_pos + 2.0, npc.y_pos + 3.0, vx, vy);
+                    // a little bump and a pause, so the catch lands
+                    game_state.camera_effects.add_trauma(0.35);
+                    game_state.camera_effects.freeze(4);
 
-                    }
-
-                    let npc_p = game_state.camera.cvt_world_to_screen_coords(npc.x_pos, npc.y_pos);
+                    let npc_p = game_state.cameras[*hit_p_i as usize].cvt_world_to_view_coords(pop_x, pop_y);
                     p.card_stack.try_push_card(abil_card_type, npc_p.0, npc_p.1);
 
 
                     let gained_amount = 1;
                     game_state.countdown_timer_msec += gained_amount * 60;
                     game_state.countdown_timer_msec = game_state.countdown_timer_msec.min(100 * 60 - 1);
-                    game_state.score += gained_amount;
+                    game_state.score += gained_amount * game_state.score_multiplier;
                 }
+                // in versus, other players' kitties have to be stolen off of them
+                Some(owner) if versus && owner != *hit_p_i => continue,
                 Some(_) => {}
             }
 
@@ -195,6 +198,184 @@ pub fn check_entity_collisions(game_state: &mut GameState) {
             npc.following_i = Some(*hit_p_i);
         }
     }
+
+    // player -> player (versus): pouncing on someone from above steals one of their kitties
+    if versus {
+        for thief in 0..game_state.players.len() {
+            for victim in 0..game_state.players.len() {
+                let pounced = match (&game_state.players[thief], &game_state.players[victim]) {
+                    (OptionallyEnabledPlayer::Enabled(a), OptionallyEnabledPlayer::Enabled(b)) if thief != victim => {
+                        a.character.y_vel > Fixed::ZERO
+                            && a.character.y_pos < b.character.y_pos
+                            && check_absolute_bounding_box_partially_inside_another(
+                                &get_bound_of_character(&a.character),
+                                &get_bound_of_character(&b.character),
+                            )
+                    }
+                    _ => false,
+                };
+                if !pounced {
+                    continue;
+                }
+                if let Some((x, y)) = game_state.versus.steal(&mut game_state.npcs, thief, victim) {
+                    game_state.particles.emit(Emitter::Sparkles, x + 2.0, y + 3.0);
+                    game_state.sfx.trigger(Sfx::KittyFound);
+                    game_state.camera_effects.add_trauma(0.25);
+                    game_state.popup_text_ringbuffer.add_new_popup(x - 7.0, y, format![" P{}", thief + 1], PopupIcon::CatHead);
+                }
+            }
+        }
+    }
+
+    // shadow cat -> player (endless): takes some time off
+    for (i, opt_p) in game_state.players.iter().enumerate() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            let player_bound = get_bound_of_character(&p.character);
+            let touched = game_state.enemies.iter().any(|enemy| {
+                check_absolute_bounding_box_partially_inside_another(&player_bound, &get_bound_of_character(enemy))
+            });
+            if touched && game_state.endless.try_hit(i) {
+                const ENEMY_HIT_SECONDS: u32 = 5;
+                // (never all the way down, running out happens with the countdown)
+                game_state.countdown_timer_msec = game_state.countdown_timer_msec.saturating_sub(ENEMY_HIT_SECONDS * 60).max(1);
+                game_state.sfx.trigger(Sfx::TimerLow);
+                game_state.camera_effects.add_trauma(0.4);
+                game_state.popup_text_ringbuffer.add_new_popup(p.character.x_pos.to_f32() - 7.0, p.character.y_pos.to_f32(), format![" -{}", ENEMY_HIT_SECONDS], PopupIcon::Clock);
+            }
+        }
+    }
+
+    // player -> pickup
+    for (p_i, opt_p) in game_state.players.iter_mut().enumerate() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            let player_bound = get_bound_of_character(&p.character);
+            for pickup in game_state.pickups.iter_mut() {
+                if pickup.collected {
+                    continue;
+                }
+                let pickup_bound = get_bound_of_pickup(pickup);
+                if !check_absolute_bounding_box_partially_inside_another(&player_bound, &pickup_bound) {
+                    continue;
+                }
+                pickup.collected = true;
+                game_state.level_tallies.count(pickup.pickup_type);
+
+                let popup_t: String;
+                let popup_icon: PopupIcon;
+                match pickup.pickup_type {
+                    PickupTypes::Clock => {
+                        game_state.countdown_timer_msec += CLOCK_PICKUP_TIME * 60;
+                        game_state.countdown_timer_msec = game_state.countdown_timer_msec.min(100 * 60 - 1);
+                        game_state.score += CLOCK_PICKUP_TIME * game_state.score_multiplier;
+                        popup_t = format![" +{}", CLOCK_PICKUP_TIME];
+                        popup_icon = PopupIcon::Clock;
+                    }
+                    PickupTypes::Fish => {
+                        game_state.score_multiplier = (game_state.score_multiplier + 1).min(MAX_SCORE_MULTIPLIER);
+                        popup_t = format![" x{}", game_state.score_multiplier];
+                        popup_icon = PopupIcon::Fish;
+                    }
+                    PickupTypes::Key => {
+                        p.keys = p.keys.saturating_add(1);
+                        popup_t = " +1".to_string();
+                        popup_icon = PopupIcon::Key;
+                    }
+                    PickupTypes::CardPack => {
+                        let card_p = game_state.cameras[p_i].cvt_world_to_view_coords(pickup.x_pos, pickup.y_pos);
+                        let mut n_added = 0;
+                        for _ in 0..CARD_PACK_SIZE {
+                            if p.card_stack.cards.len() < N_CARDS {
+                                let card_type = AbilityCardTypes::random_from_pack(game_state.rng.next_for_input());
+                                p.card_stack.try_push_card(card_type, card_p.0, card_p.1);
+                                n_added += 1;
+                            }
+                        }
+                        popup_t = format![" +{}", n_added];
+                        popup_icon = PopupIcon::CardPack;
+                    }
+                }
+
+                // spawn some clouds
+                game_state.particles.emit(Emitter::PickupPuff, pickup.x_pos, pickup.y_pos);
+                game_state.popup_text_ringbuffer.add_new_popup(pickup.x_pos - 7.0, pickup.y_pos, popup_t, popup_icon);
+            }
+        }
+    }
+
+    // player -> warp pad
+    for opt_p in game_state.players.iter() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            let player_bound = get_bound_of_character(&p.character);
+            for pad in game_state.map.warp_pads.iter_mut() {
+                if pad.discovered || !check_absolute_bounding_box_partially_inside_another(&player_bound, &get_bound_of_warp_pad(pad)) {
+                    continue;
+                }
+                pad.discovered = true;
+                game_state.popup_text_ringbuffer.add_new_popup(pad.x_pos - 14.0, pad.y_pos - 8.0, "checkpoint".to_string(), PopupIcon::None);
+            }
+        }
+    }
+
+    // player -> locked door. Bumping into a door while holding a key opens it.
+    for opt_p in game_state.players.iter_mut() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            if p.keys == 0 {
+                continue;
+            }
+            const DOOR_REACH_PX: i32 = 2;
+            let char_bound = get_bound_of_character(&p.character);
+            let player_bound = AbsoluteBoundingBox {
+                x: char_bound.x - DOOR_REACH_PX,
+                y: char_bound.y - DOOR_REACH_PX,
+                width: char_bound.width + 2 * DOOR_REACH_PX as u32,
+                height: char_bound.height + 2 * DOOR_REACH_PX as u32,
+            };
+            let map = &mut game_state.map;
+            for door_i in 0..map.doors.len() {
+                let door = &map.doors[door_i];
+                if !door.locked || !check_absolute_bounding_box_partially_inside_another(&player_bound, &get_bound_of_door(door)) {
+                    continue;
+                }
+                let locked_chunk_i = door.locked_chunk_i(&map.locked_chunk_idxs);
+                map.unlock_doors_of_chunk(locked_chunk_i);
+                p.keys -= 1;
+
+                let (x, y) = (p.character.x_pos.to_f32(), p.character.y_pos.to_f32());
+                game_state.particles.emit(Emitter::CloudBurst, x + 2.0, y + 3.0);
+                game_state.popup_text_ringbuffer.add_new_popup(x - 7.0, y, " open!".to_string(), PopupIcon::Key);
+                break;
+            }
+        }
+    }
+}
+
+pub fn get_bound_of_door(door: &LockedDoor) -> AbsoluteBoundingBox<i32, u32> {
+    AbsoluteBoundingBox {
+        x: door.bound.x * TILE_WIDTH_PX as i32,
+        y: door.bound.y * TILE_HEIGHT_PX as i32,
+        width: (door.bound.width * TILE_WIDTH_PX) as u32,
+        height: (door.bound.height * TILE_HEIGHT_PX) as u32,
+    }
+}
+
+pub fn get_bound_of_warp_pad(pad: &WarpPad) -> AbsoluteBoundingBox<i32, u32> {
+    let frame = spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::WarpPad).frames[0];
+    AbsoluteBoundingBox {
+        x: pad.x_pos as i32,
+        y: pad.y_pos as i32,
+        width: frame.width as u32,
+        height: frame.height as u32,
+    }
+}
+
+pub fn get_bound_of_pickup(pickup: &Pickup) -> AbsoluteBoundingBox<i32, u32> {
+    let frame = pickup.sprite.frames[0];
+    AbsoluteBoundingBox {
+        x: pickup.x_pos as i32,
+        y: pickup.y_pos as i32,
+        width: frame.width as u32,
+        height: frame.height as u32,
+    }
 }
 
 pub struct CollisionResult {
@@ -332,8 +513,30 @@ pub fn raycast_axis_aligned(
     collision_result
 }
 
-// handle inputs of players and other characters.
-pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode: bool, clouds:&mut Vec<Cloud>) {
+// anything outside of the map counts as solid.
+pub fn is_solid_at(map: &GameMap, x: i32, y: i32) -> bool {
+    for chunk in map.chunks.iter() {
+        match chunk.get_tile_abs(x, y) {
+            Ok(tile) => return tile != 0,
+            Err(_) => {}
+        }
+    }
+    true
+}
+
+pub fn is_area_clear(map: &GameMap, x: i32, y: i32, w: u8, h: u8) -> bool {
+    for check_y in y..y + h as i32 {
+        for check_x in x..x + w as i32 {
+            if is_solid_at(map, check_x, check_y) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// handle inputs of players and other characters. Sound effects only get played if there's somewhere to play them.
+pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode: bool, particles: &mut ParticlePool, mut sfx: Option<&mut SfxPlayer>) {
     let character: &mut Character;
 
     match moving_entity {
@@ -344,12 +547,7 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                 }
                 OptionallyEnabledPlayer::Disabled => {
                     if input != 0 {
-                        *optionally_enabled_player = OptionallyEnabledPlayer::Enabled(Player {
-                            character: Character::new(spritesheet::PresetSprites::MainCat),
-                            card_stack: AbilityCardStack {
-                                cards: Vec::new()
-                            },
-                        });
+                        *optionally_enabled_player = OptionallyEnabledPlayer::Enabled(Player::new());
                         match optionally_enabled_player {
                             OptionallyEnabledPlayer::Enabled(ch) => {
                                 character = &mut ch.character;
@@ -367,9 +565,8 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         }
     }
 
-    const BTN_ACCEL: f32 = 0.85;
-    const HOP_V: f32 = -5.0;
-    const H_DECAY: f32 = 0.92;
+    const BTN_ACCEL: Fixed = Fixed::from_ratio(85, 100);
+    const H_DECAY: Fixed = Fixed::from_ratio(92, 100);
 
     enum HorizontalMovementOutcome {
         ChangedDirection,
@@ -408,7 +605,7 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         ret
     }
 
-    fn handle_jumping(the_char: &mut Character, input: u8, clouds: &mut Vec<Cloud>) -> bool {
+    fn handle_jumping(the_char: &mut Character, input: u8, particles: &mut ParticlePool, sfx: &mut Option<&mut SfxPlayer>) -> bool {
         let mut allow_jump = true;
         match the_char.state { 
             KittyStates::JumpingUp(t) => match t {
@@ -417,10 +614,13 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                     
                     const CLOUD_VX: f32 = 2.0;
                     const CLOUD_VY: f32 = 1.0;
-                    let y = the_char.y_pos + (the_char.sprite.frames[the_char.current_sprite_i as usize].height as f32) * 1.2;
-                    let x = the_char.x_pos + (the_char.sprite.frames[the_char.current_sprite_i as usize].width as f32) * 0.5;
-                    Cloud::try_push_cloud(clouds, x, y, CLOUD_VX, CLOUD_VY);
-                    Cloud::try_push_cloud(clouds, x, y, -CLOUD_VX, CLOUD_VY);
+                    let y = the_char.y_pos.to_f32() + (the_char.sprite.frames[the_char.current_sprite_i as usize].height as f32) * 1.2;
+                    let x = the_char.x_pos.to_f32() + (the_char.sprite.frames[the_char.current_sprite_i as usize].width as f32) * 0.5;
+                    particles.emit_with_velocity(Emitter::Cloud, x, y, CLOUD_VX, CLOUD_VY);
+                    particles.emit_with_velocity(Emitter::Cloud, x, y, -CLOUD_VX, CLOUD_VY);
+                    if let Some(sfx) = sfx {
+                        sfx.trigger(Sfx::Jump);
+                    }
 
                 }
                 2..=10 => {}
@@ -435,26 +635,106 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
             allow_jump = true;
         }
 
+        // just walked off a ledge, so it's not too late to jump.
+        if the_char.coyote_timer > 0 {
+            allow_jump = true;
+        }
+
+        // a fresh press of jump while in the air uses up the double jump.
+        let jump_pressed = input & BUTTON_1 != 0 && the_char.last_input & BUTTON_1 == 0;
+        if !allow_jump && the_char.can_double_jump && !the_char.used_double_jump && jump_pressed {
+            the_char.used_double_jump = true;
+            allow_jump = true;
+        }
+
+        // jump was pressed just before landing.
+        let on_ground = match the_char.state {
+            KittyStates::Walking(_) | KittyStates::Sleeping => true,
+            _ => false,
+        };
+        let buffered_jump = on_ground && the_char.jump_buffer_timer > 0;
+
         if allow_jump {
-            if input & BUTTON_1 != 0 {
+            if input & BUTTON_1 != 0 || buffered_jump {
                 the_char.state = KittyStates::JumpingUp(0);
-                the_char.y_vel = HOP_V;
+                the_char.y_vel = the_char.movement.hop_v;
+                the_char.coyote_timer = 0;
+                the_char.jump_buffer_timer = 0;
                 return true;
             }
         }
         false
     }
 
+    // while hugging a wall, see if its top is within reach and there's room to stand up there.
+    // Gives back the x of the wall column and the y of the top of the ledge.
+    fn find_ledge(map: &GameMap, the_char: &Character) -> Option<(i32, i32)> {
+        const LEDGE_REACH_PX: i32 = 8;
+        let hugging = the_char.sprite.frames[4];
+        let standing = the_char.sprite.frames[0];
+        let x = the_char.x_pos.to_int();
+        let y = the_char.y_pos.to_int();
+        let wall_x = match the_char.is_facing_right {
+            true => x + hugging.width as i32,
+            false => x - 1,
+        };
+        let mid_y = y + hugging.height as i32 / 2;
+        if !is_solid_at(map, wall_x, mid_y) {
+            return None;
+        }
+        let mut probe_y = mid_y;
+        while probe_y >= y - LEDGE_REACH_PX {
+            if !is_solid_at(map, wall_x, probe_y) {
+                let ledge_y = probe_y + 1;
+                let stand_x = match the_char.is_facing_right {
+                    true => wall_x,
+                    false => wall_x + 1 - standing.width as i32,
+                };
+                if is_area_clear(map, stand_x, ledge_y - standing.height as i32, standing.width, standing.height) {
+                    return Some((wall_x, ledge_y));
+                }
+                return None;
+            }
+            probe_y -= 1;
+        }
+        None
+    }
+
+    // remember fresh jump presses for a few frames, in case we land soon.
+    if input & BUTTON_1 != 0 && character.last_input & BUTTON_1 == 0 {
+        character.jump_buffer_timer = character.movement.jump_buffer_frames;
+    } else {
+        character.jump_buffer_timer = character.jump_buffer_timer.saturating_sub(1);
+    }
+
     if godmode {
         character.state = KittyStates::JumpingUp(0);
-        handle_jumping(character, input, clouds);
+        handle_jumping(character, input, particles, &mut sfx);
+    }
+
+    // dunk in or climb out of water, judging by the middle of the character.
+    {
+        let frame = character.sprite.frames[character.current_sprite_i as usize];
+        let center_x = character.x_pos.to_int() + frame.width as i32 / 2;
+        let center_y = character.y_pos.to_int() + frame.height as i32 / 2;
+        let in_water = !godmode && map.is_water_at(center_x, center_y);
+        match (in_water, &character.state) {
+            (true, KittyStates::GrabbingLedge(_) | KittyStates::PullingUp(_) | KittyStates::Swimming(_)) => {}
+            (true, _) => {
+                character.state = KittyStates::Swimming(0);
+            }
+            (false, KittyStates::Swimming(_)) => {
+                character.state = KittyStates::JumpingUp(30);
+            }
+            _ => {}
+        }
     }
 
-    const GRAVITY: f32 = 0.3;
+    const GRAVITY: Fixed = Fixed::from_ratio(3, 10);
     // const HUGGING_WALL_SLIDE_MULT: f32 = 0.2;
     match character.state {
-        KittyStates::HuggingWall(_) | KittyStates::OnCeiling(_) => {
-            character.y_vel = 0.0;
+        KittyStates::HuggingWall(_) | KittyStates::OnCeiling(_) | KittyStates::GrabbingLedge(_) | KittyStates::PullingUp(_) => {
+            character.y_vel = Fixed::ZERO;
             // if character.y_vel < 0.0 {
             //     character.y_vel = 0.0;
             // }
@@ -462,6 +742,12 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
             //     character.y_vel += GRAVITY * HUGGING_WALL_SLIDE_MULT;
             // }
         }
+        KittyStates::Swimming(_) => {
+            // water pushes up a little harder than gravity pulls down, so we float.
+            const WATER_GRAVITY: Fixed = Fixed::from_ratio(3, 40);
+            const BUOYANCY: Fixed = Fixed::from_ratio(9, 40);
+            character.y_vel += WATER_GRAVITY - BUOYANCY;
+        }
         _ => {
             character.y_vel += GRAVITY;
         }
@@ -469,27 +755,68 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
 
     match character.state {
         KittyStates::JumpingUp(t) => {
-            handle_horizontal_input(character, input);
-            handle_jumping(character, input, clouds);
+            // a wall kick carries us away for a bit before steering kicks back in.
+            if character.wall_kick_timer > 0 {
+                character.wall_kick_timer -= 1;
+            } else {
+                handle_horizontal_input(character, input);
+            }
+            handle_jumping(character, input, particles, &mut sfx);
             character.state = KittyStates::JumpingUp((t + 1).min(255));
+            character.coyote_timer = character.coyote_timer.saturating_sub(1);
+
+            // letting go of jump while still going up cuts the jump short.
+            match character.movement.short_hop_v {
+                Some(short_hop_v) => {
+                    if input & BUTTON_1 == 0 && character.y_vel < -short_hop_v {
+                        character.y_vel = -short_hop_v;
+                    }
+                }
+                None => {}
+            }
         }
         KittyStates::HuggingWall(firstframe) => {
             if firstframe {
                 if character.is_facing_right {
-                    character.x_pos += character.sprite.frames[3].width as f32
-                        - character.sprite.frames[4].width as f32;
+                    character.x_pos += Fixed::from_int(character.sprite.frames[3].width as i32
+                        - character.sprite.frames[4].width as i32);
                 }
             }
             character.state = KittyStates::HuggingWall(false);
-            let ret = handle_horizontal_input(character, input);
-            match ret {
-                HorizontalMovementOutcome::ChangedDirection => {
+
+            let ledge = match character.movement.ledge_grab {
+                true => find_ledge(map, character),
+                false => None,
+            };
+            let facing_wall = character.is_facing_right;
+            let ret = match ledge {
+                Some(_) => HorizontalMovementOutcome::DoingSameThing,
+                None => handle_horizontal_input(character, input),
+            };
+            // pressing away from the wall and jumping kicks off of it.
+            let kicking_off = character.movement.wall_kick_frames > 0 && input & BUTTON_1 != 0;
+            match (ledge, ret) {
+                (Some((wall_x, ledge_y)), _) => {
+                    // hang with our paws just over the top of the wall.
+                    let hanging = character.sprite.frames[6];
+                    character.x_pos = Fixed::from_int(match character.is_facing_right {
+                        true => wall_x - hanging.width as i32,
+                        false => wall_x + 1,
+                    });
+                    character.y_pos = Fixed::from_int(ledge_y - 2);
+                    character.x_vel = Fixed::ZERO;
+                    character.y_vel = Fixed::ZERO;
+                    character.state = KittyStates::GrabbingLedge(0);
+                }
+                (None, HorizontalMovementOutcome::ChangedDirection) if !kicking_off => {
                     character.state = KittyStates::JumpingUp(0);
                 }
                 _ => {
-                    if handle_jumping(character, input, clouds) {
+                    character.is_facing_right = facing_wall;
+                    if handle_jumping(character, input, particles, &mut sfx) {
+                        character.wall_kick_timer = character.movement.wall_kick_frames;
                         character.is_facing_right = !character.is_facing_right;
-                        const WALLJUMP_VX: f32 = 3.0;
+                        const WALLJUMP_VX: Fixed = Fixed::from_int(3);
                         let new_x_vel = match character.is_facing_right {
                             true => WALLJUMP_VX,
                             false => -WALLJUMP_VX,
@@ -497,8 +824,8 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                         character.x_vel = new_x_vel;
                         // #TODO find better spacing fix for walljump on right.
                         if !character.is_facing_right {
-                            character.x_pos -= character.sprite.frames[3].width as f32
-                                - character.sprite.frames[4].width as f32;
+                            character.x_pos -= Fixed::from_int(character.sprite.frames[3].width as i32
+                                - character.sprite.frames[4].width as i32);
                         }
                     }
                 }
@@ -512,7 +839,7 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                 }
                 _ => {}
             }
-            handle_jumping(character, input, clouds);
+            handle_jumping(character, input, particles, &mut sfx);
         }
         KittyStates::Walking(t) => {
             let ret = handle_horizontal_input(character, input);
@@ -531,36 +858,135 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                     // character.state = KittyStates::Sleeping;
                 }
             }
-            handle_jumping(character, input, clouds);
+            handle_jumping(character, input, particles, &mut sfx);
         },
         KittyStates::OnCeiling(t) => {
             let ret = handle_horizontal_input(character, input);
 
             match ret {
                 HorizontalMovementOutcome::DoingSameThing => {
-                    character.state = KittyStates::OnCeiling((t + 1) % 255);
+                    character.state = KittyStates::OnCeiling(t.wrapping_add(1) % 255);
                 }
                 HorizontalMovementOutcome::ChangedDirection => {
                     character.state = KittyStates::OnCeiling(0);
                 }
                 HorizontalMovementOutcome::StoppedMoving => {
-                    character.state = KittyStates::Sleeping;
+                    // crawlers keep hanging on when they stop.
+                    if !character.movement.ceiling_crawl {
+                        character.state = KittyStates::Sleeping;
+                    }
                 }
                 _ => {
                     // character.state = KittyStates::Sleeping;
                 }
             }
+
+            if character.movement.ceiling_crawl {
+                const CRAWL_V: Fixed = Fixed::from_int(1);
+                character.x_vel = character.x_vel.clamp(-CRAWL_V, CRAWL_V);
+                if input & BUTTON_DOWN != 0 {
+                    character.state = KittyStates::JumpingUp(30);
+                }
+            }
             
             if t > 30 {
-                handle_jumping(character, input, clouds);
+                handle_jumping(character, input, particles, &mut sfx);
             }
             
         },
+        KittyStates::Swimming(t) => {
+            const SWIM_V_CAP: Fixed = Fixed::from_ratio(3, 2);
+            const STROKE_V: Fixed = Fixed::from_int(2);
+            const DIVE_ACCEL: Fixed = Fixed::from_ratio(2, 5);
+            const WATER_DRAG: Fixed = Fixed::from_ratio(9, 10);
+            // how close to the surface we need to be to hop out.
+            const SURFACE_PX: i32 = 6;
+
+            handle_horizontal_input(character, input);
+            character.x_vel = character.x_vel.clamp(-SWIM_V_CAP, SWIM_V_CAP);
+
+            let frame = character.sprite.frames[character.current_sprite_i as usize];
+            let center_x = character.x_pos.to_int() + frame.width as i32 / 2;
+            let center_y = character.y_pos.to_int() + frame.height as i32 / 2;
+            let near_surface = !map.is_water_at(center_x, center_y - SURFACE_PX);
+
+            character.state = KittyStates::Swimming(t.wrapping_add(1));
+            if input & BUTTON_1 != 0 && character.last_input & BUTTON_1 == 0 {
+                if near_surface {
+                    character.state = KittyStates::JumpingUp(0);
+                    character.y_vel = character.movement.hop_v;
+                } else {
+                    character.y_vel -= STROKE_V;
+                }
+            }
+            if input & BUTTON_DOWN != 0 {
+                character.y_vel += DIVE_ACCEL;
+            }
+            character.x_vel *= WATER_DRAG;
+            character.y_vel *= WATER_DRAG;
+
+            // bubbles
+            const BUBBLE_PERIOD: u8 = 24;
+            if t % BUBBLE_PERIOD == 0 {
+                const BUBBLE_VY: f32 = -2.0;
+                let x = match character.is_facing_right {
+                    true => (character.x_pos.to_int() + frame.width as i32) as f32,
+                    false => character.x_pos.to_f32(),
+                };
+                particles.emit_with_velocity(Emitter::Cloud, x, character.y_pos.to_f32(), 0.0, BUBBLE_VY);
+            }
+        }
+        KittyStates::GrabbingLedge(t) => {
+            // give the grab a moment to show before taking input.
+            const GRAB_SETTLE_FRAMES: u8 = 4;
+            character.state = KittyStates::GrabbingLedge(t.saturating_add(1));
+            if t >= GRAB_SETTLE_FRAMES {
+                let toward_wall = match character.is_facing_right {
+                    true => BUTTON_RIGHT,
+                    false => BUTTON_LEFT,
+                };
+                let away_from_wall = match character.is_facing_right {
+                    true => BUTTON_LEFT,
+                    false => BUTTON_RIGHT,
+                };
+                if input & (BUTTON_UP | BUTTON_1 | toward_wall) != 0 {
+                    character.state = KittyStates::PullingUp(0);
+                } else if input & (BUTTON_DOWN | away_from_wall) != 0 {
+                    character.state = KittyStates::JumpingUp(30);
+                }
+            }
+        }
+        KittyStates::PullingUp(t) => {
+            const PULL_UP_FRAMES: u8 = 8;
+            if t + 1 >= PULL_UP_FRAMES {
+                // stand up on top of the ledge we were hanging from.
+                let hanging = character.sprite.frames[6];
+                let standing = character.sprite.frames[0];
+                let x = character.x_pos.to_int();
+                let ledge_y = character.y_pos.to_int() + 2;
+                character.x_pos = Fixed::from_int(match character.is_facing_right {
+                    true => x + hanging.width as i32,
+                    false => x - standing.width as i32,
+                });
+                character.y_pos = Fixed::from_int(ledge_y - standing.height as i32);
+                character.x_vel = Fixed::ZERO;
+                character.state = KittyStates::Sleeping;
+            } else {
+                character.state = KittyStates::PullingUp(t + 1);
+            }
+        }
     }
 
     fn get_sprite_i_from_anim_state(state: &KittyStates, discrete_y_vel: i32) -> i32 {
         match state {
             KittyStates::HuggingWall(_) => 4,
+            KittyStates::GrabbingLedge(_) => 6,
+            KittyStates::PullingUp(_) => 7,
+            // paddle slower than walking
+            KittyStates::Swimming(t) => match (t / 10) % 2 {
+                0 => 1,
+                _ => 2,
+            },
             KittyStates::JumpingUp(t) => {
                 match t {
                     0 => 0,
@@ -589,20 +1015,20 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         }
     }
 
-    character.x_vel = num::clamp(character.x_vel, -character.x_vel_cap, character.x_vel_cap);
-    character.y_vel = num::clamp(character.y_vel, -character.y_vel_cap, character.y_vel_cap);
+    character.x_vel = character.x_vel.clamp(-character.x_vel_cap, character.x_vel_cap);
+    character.y_vel = character.y_vel.clamp(-character.y_vel_cap, character.y_vel_cap);
 
     // now, we need to check if moving in the current direction would collide with anything.
     // Since before moving we can assume we are in a valid location, as long as this collision
     // logic places us in another valid location, we'll be okay.
 
-    let mut discretized_y_displacement_this_frame = character.y_vel as i32;
-    let mut discretized_x_displacement_this_frame = character.x_vel as i32;
+    let mut discretized_y_displacement_this_frame = character.y_vel.to_int();
+    let mut discretized_x_displacement_this_frame = character.x_vel.to_int();
 
     // hotfix: if our y displacement is exactly zero, set it to 1, just so we
     // can properly check if we're colliding with the ground.
     match character.state {
-        KittyStates::HuggingWall(_) => {}
+        KittyStates::HuggingWall(_) | KittyStates::GrabbingLedge(_) | KittyStates::PullingUp(_) | KittyStates::Swimming(_) => {}
         KittyStates::OnCeiling(_) => {
             if discretized_y_displacement_this_frame == 0 {
                 discretized_y_displacement_this_frame = -1;
@@ -723,13 +1149,16 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                     // if we collided against the top, automatically hang
                     if positive_y == false {
                         character.state = match character.state {
-                            KittyStates::OnCeiling(t) => KittyStates::OnCeiling(t+1),
+                            KittyStates::OnCeiling(t) => KittyStates::OnCeiling(t.wrapping_add(1)),
                             KittyStates::HuggingWall(t) => KittyStates::HuggingWall(t),
+                            KittyStates::GrabbingLedge(t) => KittyStates::GrabbingLedge(t),
+                            KittyStates::PullingUp(t) => KittyStates::PullingUp(t),
+                            KittyStates::Swimming(t) => KittyStates::Swimming(t),
                             _ => KittyStates::OnCeiling(0),
                         };
                     }
 
-                    character.y_vel = 0.0;
+                    character.y_vel = Fixed::ZERO;
                     if v_col_res_left.backed_up {
                         discretized_y_displacement_this_frame =
                             v_col_res_left.allowable_displacement;
@@ -759,7 +1188,7 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                         discretized_y_displacement_this_frame -= TILE_HEIGHT_PX as i32;
                     } else {
                         // if the above special case isn't true, we hit a wall
-                        character.x_vel = 0.0;
+                        character.x_vel = Fixed::ZERO;
 
                         // if in free fall (after beginning of jump), allow hugging wall
                         match character.state {
@@ -895,8 +1324,10 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         // if anyone makes it out of bounds, drop them in the center of the map
         // (or if they use the lizard warp)
         if !inside_at_least_one_chunk {
-            character.x_pos = 10.0;
-            character.y_pos = 10.0;
+            character.x_pos = character.last_safe_x_pos;
+            character.y_pos = character.last_safe_y_pos;
+            character.x_vel = Fixed::ZERO;
+            character.y_vel = Fixed::ZERO;
         }
     }
 
@@ -905,6 +1336,7 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         match character.state {
             KittyStates::Walking(_) | KittyStates::Sleeping => {
                 character.state = KittyStates::JumpingUp(30);
+                character.coyote_timer = character.movement.coyote_frames;
             }
             KittyStates::OnCeiling(_) => {
                 character.state = KittyStates::JumpingUp(0);
@@ -918,11 +1350,17 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
         // if we hit the floor, stop jumping
         match character.state {
             KittyStates::JumpingUp(t) => match t {
-                0..=15 => {
-                    // Cloud::try_push_cloud(clouds, 0.0, 0.0, 5.0, 0.0);
-                }
+                0..=15 => {}
                 _ => {
                     character.state = KittyStates::Walking(0);
+                    // kick up some dust from under the feet
+                    let frame = character.sprite.frames[character.current_sprite_i as usize];
+                    let x = character.x_pos.to_f32() + frame.width as f32 * 0.5;
+                    let y = character.y_pos.to_f32() + frame.height as f32;
+                    particles.emit(Emitter::Dust, x, y);
+                    if let Some(sfx) = &mut sfx {
+                        sfx.trigger(Sfx::Land);
+                    }
                 }
             },
             _ => {}
@@ -937,14 +1375,35 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
                 match warp_state {
                     WarpState::Charging(t) => {
                         *t += 1;
+                        if *t == 1 {
+                            if let Some(sfx) = &mut sfx {
+                                sfx.trigger(Sfx::WarpCharge);
+                            }
+                        }
                         if *t >= 25 {
                             character.warp_ability = WarpAbility::CanWarp(WarpState::Ready);
                         }
                     },
                     WarpState::Ready => {
-                        character.x_pos = 10.0;
-                        character.y_pos = 10.0;
+                        particles.emit(Emitter::WarpSwirl, character.x_pos.to_f32() + 2.0, character.y_pos.to_f32() + 3.0);
+                        // keep holding down to cycle through the discovered warp pads
+                        match map.next_discovered_warp_pad(character.warp_pad_i) {
+                            Some(pad_i) => {
+                                const WARP_LANDING_HEIGHT: i32 = 10;
+                                let pad = &map.warp_pads[pad_i];
+                                character.x_pos = Fixed::from_int(pad.x_pos as i32);
+                                character.y_pos = Fixed::from_int(pad.y_pos as i32 - WARP_LANDING_HEIGHT);
+                                character.warp_pad_i = Some(pad_i);
+                            }
+                            None => {
+                                character.x_pos = Fixed::from_int(10);
+                                character.y_pos = Fixed::from_int(10);
+                            }
+                        }
+                        character.x_vel = Fixed::ZERO;
+                        character.y_vel = Fixed::ZERO;
                         character.warp_ability = WarpAbility::CanWarp(WarpState::Charging(0));
+                        particles.emit(Emitter::WarpSwirl, character.x_pos.to_f32() + 2.0, character.y_pos.to_f32() + 3.0);
                     }
                 }
             }
@@ -956,12 +1415,379 @@ pub fn update_pos(map: &GameMap, moving_entity: MovingEntity, input: u8, godmode
             },
             _ => {}
         }
+        character.warp_pad_i = None;
     }
 
     // character.current_sprite_i = get_sprite_i_from_anim_state(&character.state, discretized_y_displacement_this_frame);
 
-    character.x_pos += discretized_x_displacement_this_frame as f32;
-    character.y_pos += discretized_y_displacement_this_frame as f32;
+    character.x_pos += Fixed::from_int(discretized_x_displacement_this_frame);
+    character.y_pos += Fixed::from_int(discretized_y_displacement_this_frame);
+
+    if touching_some_ground && !godmode {
+        character.last_safe_x_pos = character.x_pos;
+        character.last_safe_y_pos = character.y_pos;
+    }
+
+    // the double jump comes back once we're standing on (or clinging to) something.
+    match character.state {
+        KittyStates::JumpingUp(_) => {}
+        _ => {
+            character.used_double_jump = false;
+        }
+    }
+    character.last_input = input;
 
     character.count += 1;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::entities::MovementProfile;
+    use crate::game::effects::{ActiveEffects, EffectTypes};
+
+    const SOLID: u8 = 9;
+
+    // a tall block on the left to walk off of, and a floor to land on.
+    fn ledge_map() -> GameMap {
+        let mut map = GameMap::create_map();
+        let mut chunk = MapChunk::init();
+        chunk.bound = TileAlignedBoundingBox::init(0, 0, 40, 30);
+        chunk.initialize();
+        for col in 0..40 {
+            chunk.set_tile(col, 29, SOLID);
+        }
+        for col in 0..10 {
+            for row in 14..29 {
+                chunk.set_tile(col, row, SOLID);
+            }
+        }
+        map.add_chunk(chunk);
+        map
+    }
+
+    fn character_at(x: i32, y: i32, movement: MovementProfile) -> Character {
+        spritesheet::Sprite::init_all_sprites_for_tests();
+        let mut ch = Character::new(spritesheet::PresetSprites::MainCat);
+        ch.x_pos = Fixed::from_int(x);
+        ch.y_pos = Fixed::from_int(y);
+        ch.movement = movement;
+        ch
+    }
+
+    fn step(map: &GameMap, ch: &mut Character, input: u8) {
+        let mut particles = ParticlePool::new();
+        update_pos(map, MovingEntity::NPC(ch), input, false, &mut particles, None);
+    }
+
+    fn on_ground(ch: &Character) -> bool {
+        match ch.state {
+            KittyStates::Walking(_) | KittyStates::Sleeping => true,
+            _ => false,
+        }
+    }
+
+    fn settle(map: &GameMap, ch: &mut Character) {
+        for _ in 0..200 {
+            step(map, ch, 0);
+        }
+        assert!(on_ground(ch));
+    }
+
+    // walk off the ledge, then press jump a few frames later. Returns if that jump happened.
+    fn late_jump_off_ledge(movement: MovementProfile) -> bool {
+        let map = ledge_map();
+        let mut ch = character_at(20, 40, movement);
+        settle(&map, &mut ch);
+        while on_ground(&ch) {
+            step(&map, &mut ch, BUTTON_RIGHT);
+        }
+        for _ in 0..3 {
+            step(&map, &mut ch, BUTTON_RIGHT);
+        }
+        step(&map, &mut ch, BUTTON_RIGHT | BUTTON_1);
+        ch.y_vel < Fixed::ZERO
+    }
+
+    #[test]
+    fn coyote_time_allows_late_jumps() {
+        assert!(late_jump_off_ledge(MovementProfile::PLAYER));
+        assert!(!late_jump_off_ledge(MovementProfile::CLASSIC));
+    }
+
+    // tap jump a few frames before landing. Returns if a jump happened after landing.
+    fn early_jump_before_landing(movement: MovementProfile) -> bool {
+        let map = ledge_map();
+        let mut n_frames_to_land = 0;
+        {
+            let mut ch = character_at(120, 60, movement);
+            while !on_ground(&ch) {
+                step(&map, &mut ch, 0);
+                n_frames_to_land += 1;
+            }
+        }
+
+        let mut ch = character_at(120, 60, movement);
+        for frame in 0..n_frames_to_land + 5 {
+            let input = match frame == n_frames_to_land - 3 {
+                true => BUTTON_1,
+                false => 0,
+            };
+            step(&map, &mut ch, input);
+            if frame > n_frames_to_land - 3 && ch.y_vel < Fixed::ZERO {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn jump_buffer_remembers_early_presses() {
+        assert!(early_jump_before_landing(MovementProfile::PLAYER));
+        assert!(!early_jump_before_landing(MovementProfile::CLASSIC));
+    }
+
+    // the highest point (smallest y) reached when holding jump for some frames.
+    fn apex_of_jump(movement: MovementProfile, hold_frames: u32) -> Fixed {
+        let map = ledge_map();
+        let mut ch = character_at(120, 100, movement);
+        settle(&map, &mut ch);
+        let mut apex = ch.y_pos;
+        for frame in 0..60 {
+            let input = match frame < hold_frames {
+                true => BUTTON_1,
+                false => 0,
+            };
+            step(&map, &mut ch, input);
+            apex = apex.min(ch.y_pos);
+        }
+        apex
+    }
+
+    #[test]
+    fn releasing_jump_early_makes_a_short_hop() {
+        let tap = apex_of_jump(MovementProfile::PLAYER, 1);
+        let held = apex_of_jump(MovementProfile::PLAYER, 20);
+        assert!(tap > held);
+        // the classic profile goes higher on a tap
+        assert!(apex_of_jump(MovementProfile::CLASSIC, 1) < tap);
+    }
+
+    // fall alongside the tall block while holding toward it, then keep holding.
+    fn climb_tall_block(movement: MovementProfile) -> Character {
+        let map = ledge_map();
+        let mut ch = character_at(58, 70, movement);
+        for _ in 0..60 {
+            step(&map, &mut ch, BUTTON_LEFT);
+        }
+        ch
+    }
+
+    #[test]
+    fn ledge_grab_pulls_up_onto_the_wall() {
+        const BLOCK_TOP_Y: i32 = 70;
+        let ch = climb_tall_block(MovementProfile::PLAYER);
+        assert!(on_ground(&ch));
+        assert!(ch.y_pos.to_int() < BLOCK_TOP_Y);
+
+        // without ledge grabs, the cat just clings to the side.
+        let ch = climb_tall_block(MovementProfile::CLASSIC);
+        assert!(ch.state == KittyStates::HuggingWall(false));
+        assert!(ch.y_pos.to_int() > BLOCK_TOP_Y);
+    }
+
+    // cling low on the tall block, jump, then hold back toward the wall.
+    // Returns how far from the wall we got.
+    fn kick_off_wall(movement: MovementProfile) -> i32 {
+        let map = ledge_map();
+        let mut ch = character_at(56, 100, movement);
+        for _ in 0..30 {
+            step(&map, &mut ch, BUTTON_LEFT);
+        }
+        assert!(ch.state == KittyStates::HuggingWall(false));
+        let start_x = ch.x_pos.to_int();
+        step(&map, &mut ch, BUTTON_LEFT | BUTTON_1);
+        for _ in 0..6 {
+            step(&map, &mut ch, BUTTON_LEFT | BUTTON_1);
+        }
+        ch.x_pos.to_int() - start_x
+    }
+
+    #[test]
+    fn wall_kick_carries_away_from_the_wall() {
+        let kick = kick_off_wall(MovementProfile::PLAYER);
+        let classic = kick_off_wall(MovementProfile::CLASSIC);
+        assert!(kick > classic);
+        assert!(kick >= 6);
+    }
+
+    // a low ceiling to jump up into.
+    fn ceiling_map() -> GameMap {
+        let mut map = GameMap::create_map();
+        let mut chunk = MapChunk::init();
+        chunk.bound = TileAlignedBoundingBox::init(0, 0, 40, 30);
+        chunk.initialize();
+        for col in 0..40 {
+            chunk.set_tile(col, 29, SOLID);
+            chunk.set_tile(col, 20, SOLID);
+            chunk.set_tile(col, 21, SOLID);
+        }
+        map.add_chunk(chunk);
+        map
+    }
+
+    // hold jump from the floor up into the ceiling.
+    fn jump_into_ceiling(map: &GameMap, movement: MovementProfile) -> Character {
+        let mut ch = character_at(60, 130, movement);
+        settle(map, &mut ch);
+        for _ in 0..20 {
+            step(map, &mut ch, BUTTON_1);
+        }
+        ch
+    }
+
+    fn on_ceiling(ch: &Character) -> bool {
+        match ch.state {
+            KittyStates::OnCeiling(_) => true,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn ceiling_crawl_hangs_and_moves_along() {
+        let map = ceiling_map();
+        let mut ch = jump_into_ceiling(&map, MovementProfile::PLAYER);
+        assert!(on_ceiling(&ch));
+        for _ in 0..60 {
+            step(&map, &mut ch, 0);
+        }
+        assert!(on_ceiling(&ch));
+
+        let start_x = ch.x_pos;
+        for _ in 0..20 {
+            step(&map, &mut ch, BUTTON_RIGHT);
+        }
+        assert!(on_ceiling(&ch));
+        assert!(ch.x_pos > start_x);
+
+        // down lets go.
+        step(&map, &mut ch, BUTTON_DOWN);
+        settle(&map, &mut ch);
+
+        // without crawling, the cat drops as soon as nothing is held.
+        let mut ch = jump_into_ceiling(&map, MovementProfile::CLASSIC);
+        for _ in 0..60 {
+            step(&map, &mut ch, 0);
+        }
+        assert!(on_ground(&ch));
+    }
+
+    // a floor with water up to the middle of the chunk.
+    const WATER_TOP_ROW: usize = 15;
+    fn pool_map() -> GameMap {
+        let mut map = GameMap::create_map();
+        let mut chunk = MapChunk::init();
+        chunk.bound = TileAlignedBoundingBox::init(0, 0, 40, 30);
+        chunk.initialize();
+        for col in 0..40 {
+            chunk.set_tile(col, 29, SOLID);
+        }
+        chunk.water_top_row = Some(WATER_TOP_ROW);
+        map.add_chunk(chunk);
+        map
+    }
+
+    fn swimming(ch: &Character) -> bool {
+        match ch.state {
+            KittyStates::Swimming(_) => true,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn cats_float_up_and_hop_out_of_water() {
+        const SURFACE_Y: i32 = (WATER_TOP_ROW * TILE_HEIGHT_PX) as i32;
+        let map = pool_map();
+        let mut ch = character_at(100, 130, MovementProfile::PLAYER);
+        step(&map, &mut ch, 0);
+        assert!(swimming(&ch));
+        for _ in 0..120 {
+            step(&map, &mut ch, 0);
+        }
+        // bobbing at the surface
+        assert!((ch.y_pos.to_int() - SURFACE_Y).abs() < 10);
+
+        let mut apex = ch.y_pos.to_int();
+        step(&map, &mut ch, BUTTON_1);
+        for _ in 0..30 {
+            step(&map, &mut ch, BUTTON_1);
+            apex = apex.min(ch.y_pos.to_int());
+        }
+        assert!(apex < SURFACE_Y - 20);
+    }
+
+    #[test]
+    fn holding_down_dives() {
+        let map = pool_map();
+        let mut ch = character_at(100, 80, MovementProfile::PLAYER);
+        for _ in 0..120 {
+            step(&map, &mut ch, BUTTON_DOWN);
+        }
+        // resting on the bottom of the pool
+        assert!(ch.y_pos.to_int() > 130);
+    }
+
+    #[test]
+    fn water_stops_at_the_edge_of_the_chunk() {
+        let map = pool_map();
+        assert!(map.is_water_at(0, 100));
+        assert!(map.is_water_at(199, 100));
+        assert!(!map.is_water_at(-3, 100));
+        assert!(!map.is_water_at(200, 100));
+        assert!(!map.is_water_at(100, WATER_TOP_ROW as i32 * TILE_HEIGHT_PX as i32 - 1));
+    }
+
+    // a floor, with a ceiling over the middle that only leaves a one-tile gap.
+    fn tunnel_map() -> GameMap {
+        let mut map = GameMap::create_map();
+        let mut chunk = MapChunk::init();
+        chunk.bound = TileAlignedBoundingBox::init(0, 0, 40, 30);
+        chunk.initialize();
+        for col in 0..40 {
+            chunk.set_tile(col, 29, SOLID);
+        }
+        for col in 15..25 {
+            chunk.set_tile(col, 27, SOLID);
+        }
+        map.add_chunk(chunk);
+        map
+    }
+
+    #[test]
+    fn shrunk_cat_only_grows_back_where_it_fits() {
+        const FLOOR_Y: i32 = 29 * TILE_HEIGHT_PX as i32;
+        let map = tunnel_map();
+        let mut ch = character_at(100, 0, MovementProfile::PLAYER);
+        let mut effects = ActiveEffects::new();
+        effects.add(EffectTypes::Shrink, 1, &mut ch);
+        let small_height = get_bound_of_character(&ch).height as i32;
+        ch.y_pos = Fixed::from_int(FLOOR_Y - small_height);
+
+        // it runs out under the low ceiling, so the cat stays small
+        for _ in 0..10 {
+            effects.tick(&mut ch, &map);
+        }
+        assert!(effects.is_active(EffectTypes::Shrink));
+        assert_eq!(get_bound_of_character(&ch).height as i32, small_height);
+
+        // out in the open, it grows back up from where it's standing, clear of the tiles
+        ch.x_pos = Fixed::from_int(150);
+        effects.tick(&mut ch, &map);
+        assert!(!effects.is_active(EffectTypes::Shrink));
+        let bound = get_bound_of_character(&ch);
+        assert!(bound.height as i32 > small_height);
+        assert_eq!(bound.y + bound.height as i32, FLOOR_Y);
+        assert!(is_area_clear(&map, bound.x, bound.y, bound.width as u8, bound.height as u8));
+        settle(&map, &mut ch);
+    }
+}
@@ -0,0 +1,103 @@
+use super::game_constants::LEVELS_PER_MOOD;
+
+// The daily challenge: everyone on the same day gets the same maps, and one go at them.
+// It plays like timed mode, and keeps track of how each level went, to share at the end.
+pub struct DailyRun {
+    // how long each cleared level took, in frames
+    pub level_frames: Vec<u32>,
+    // the speedrun clock when the current level started
+    pub level_start_frame: u32,
+    // the result to print out, once the run is over
+    pub share_text: Option<String>,
+}
+
+// the run's seed is how many numbers get drawn before starting, so it can't get too big.
+// It starts past the seeds people would pick by hand in seed mode.
+pub fn daily_seed(day: u16) -> u32 {
+    const FIRST_SEED: u32 = 1000;
+    const N_SEEDS: u32 = 50000;
+    FIRST_SEED + (day as u32).wrapping_mul(2654435761) % N_SEEDS
+}
+
+impl DailyRun {
+    pub fn new() -> DailyRun {
+        DailyRun {
+            level_frames: Vec::new(),
+            level_start_frame: 0,
+            share_text: None,
+        }
+    }
+
+    pub fn clear_level(self: &mut Self, frame: u32) {
+        self.level_frames.push(frame - self.level_start_frame);
+        self.level_start_frame = frame;
+    }
+
+    pub fn finish(self: &mut Self, day: u16, total_frames: u32, won: bool) {
+        self.share_text = Some(share_text(day, &self.level_frames, total_frames, won));
+    }
+}
+
+// Something like "Kitty Daily #12: W2-L3 in 3:05", then a row of squares for each world, one for each level:
+// quick clears are green, then yellow and orange for slower ones, and black for where the time ran out.
+pub fn share_text(day: u16, level_frames: &[u32], total_frames: u32, won: bool) -> String {
+    const QUICK_FRAMES: u32 = 20 * 60;
+    const OK_FRAMES: u32 = 40 * 60;
+    // (patterns can't add, so the range after the quick ones gets its own start)
+    const AFTER_QUICK_FRAMES: u32 = QUICK_FRAMES + 1;
+
+    let last_level = match won {
+        true => level_frames.len().max(1),
+        false => level_frames.len() + 1,
+    };
+    let seconds = total_frames / 60;
+    let mut text = format![
+        "Kitty Daily #{}: W{}-L{} in {}:{:02}",
+        day,
+        (last_level - 1) / LEVELS_PER_MOOD + 1,
+        (last_level - 1) % LEVELS_PER_MOOD + 1,
+        seconds / 60,
+        seconds % 60
+    ];
+
+    let mut squares: Vec<&str> = level_frames
+        .iter()
+        .map(|&frames| match frames {
+            0..=QUICK_FRAMES => "\u{1f7e9}",
+            AFTER_QUICK_FRAMES..=OK_FRAMES => "\u{1f7e8}",
+            _ => "\u{1f7e7}",
+        })
+        .collect();
+    if !won {
+        squares.push("\u{2b1b}");
+    }
+    for (i, square) in squares.iter().enumerate() {
+        if i % LEVELS_PER_MOOD == 0 {
+            text.push('\n');
+        }
+        text.push_str(square);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_text_has_the_result_and_a_square_per_level() {
+        let mut daily = DailyRun::new();
+        for frame in [600, 2400, 6000, 6600, 7200, 7800] {
+            daily.clear_level(frame);
+        }
+        daily.finish(12, 8000, false);
+        assert_eq!(
+            daily.share_text.unwrap(),
+            "Kitty Daily #12: W2-L2 in 2:13\n\u{1f7e9}\u{1f7e8}\u{1f7e7}\u{1f7e9}\u{1f7e9}\n\u{1f7e9}\u{2b1b}"
+        );
+
+        // different days get different maps, and stay small enough to seed with
+        assert_ne!(daily_seed(1), daily_seed(2));
+        assert!((1..1000).all(|day| daily_seed(day) < 51000));
+    }
+}
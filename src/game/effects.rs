@@ -0,0 +1,187 @@
+use crate::spritesheet::{self, PresetSprites};
+
+use super::{collision::is_area_clear, entities::{Character, WarpAbility, WarpState}, fixed::Fixed, game_map::GameMap};
+
+// every power a player can have for a while.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum EffectTypes {
+    Fly,
+    Warp,
+    Dash,
+    DoubleJump,
+    Magnet,
+    Reveal,
+    FreezeTimer,
+    Shrink,
+}
+
+// what happens when an effect is applied again while it's still running.
+pub enum StackingRule {
+    // time left is added together (up to the effect's max)
+    AddTime,
+    // time left starts over, with the new duration
+    Restart,
+    // whichever has more time left wins
+    KeepLongest,
+}
+
+pub struct ActiveEffect {
+    pub effect_type: EffectTypes,
+    pub frames_left: u16,
+    // the most time this effect has had left, for drawing its countdown bar.
+    pub full_frames: u16,
+}
+
+pub struct ActiveEffects {
+    pub effects: Vec<ActiveEffect>,
+}
+
+const DASH_X_VEL_CAP: Fixed = Fixed::from_ratio(7, 2);
+const NORMAL_X_VEL_CAP: Fixed = Fixed::from_int(2);
+
+impl EffectTypes {
+    pub fn stacking_rule(self: &Self) -> StackingRule {
+        match self {
+            EffectTypes::Fly | EffectTypes::Warp | EffectTypes::Dash | EffectTypes::DoubleJump | EffectTypes::Shrink => StackingRule::AddTime,
+            EffectTypes::Magnet | EffectTypes::Reveal => StackingRule::Restart,
+            EffectTypes::FreezeTimer => StackingRule::KeepLongest,
+        }
+    }
+
+    pub fn max_frames(self: &Self) -> u16 {
+        match self {
+            EffectTypes::FreezeTimer => 10 * 60,
+            _ => 60 * 60,
+        }
+    }
+
+    pub fn icon(self: &Self) -> PresetSprites {
+        match self {
+            EffectTypes::Fly => PresetSprites::BirdCard,
+            EffectTypes::Warp => PresetSprites::LizardCard,
+            EffectTypes::Dash => PresetSprites::DashCard,
+            EffectTypes::DoubleJump => PresetSprites::DoubleJumpCard,
+            EffectTypes::Magnet => PresetSprites::MagnetCard,
+            EffectTypes::Reveal => PresetSprites::RevealCard,
+            EffectTypes::FreezeTimer => PresetSprites::FreezeCard,
+            EffectTypes::Shrink => PresetSprites::ShrinkCard,
+        }
+    }
+
+    // called when the effect starts. Effects that are only checked for (magnet, reveal, freeze) don't touch the character.
+    pub fn on_start(self: &Self, character: &mut Character) {
+        match self {
+            EffectTypes::Fly => character.can_fly = true,
+            EffectTypes::Warp => {
+                if character.warp_ability == WarpAbility::CannotWarp {
+                    character.warp_ability = WarpAbility::CanWarp(WarpState::Charging(0));
+                }
+            }
+            EffectTypes::Dash => character.x_vel_cap = DASH_X_VEL_CAP,
+            EffectTypes::DoubleJump => character.can_double_jump = true,
+            // borrow a lil kitty's sprite, which has a smaller bounding box to fit through gaps.
+            EffectTypes::Shrink => character.sprite = spritesheet::Sprite::from_preset(&PresetSprites::Kitty1),
+            EffectTypes::Magnet | EffectTypes::Reveal | EffectTypes::FreezeTimer => {}
+        }
+    }
+
+    // whether the effect can run out now. A shrunk cat stays small until there's room to grow back.
+    pub fn can_expire(self: &Self, character: &Character, map: &GameMap) -> bool {
+        match self {
+            EffectTypes::Shrink => {
+                let (x, y, w, h) = grown_bound(character);
+                is_area_clear(map, x, y, w, h)
+            }
+            _ => true,
+        }
+    }
+
+    // called when the effect runs out, to undo on_start.
+    pub fn on_expire(self: &Self, character: &mut Character) {
+        match self {
+            EffectTypes::Fly => character.can_fly = false,
+            EffectTypes::Warp => character.warp_ability = WarpAbility::CannotWarp,
+            EffectTypes::Dash => character.x_vel_cap = NORMAL_X_VEL_CAP,
+            EffectTypes::DoubleJump => character.can_double_jump = false,
+            EffectTypes::Shrink => {
+                let (_, y, _, _) = grown_bound(character);
+                character.y_pos = Fixed::from_int(y);
+                character.sprite = spritesheet::Sprite::from_preset(&character.sprite_type);
+            }
+            EffectTypes::Magnet | EffectTypes::Reveal | EffectTypes::FreezeTimer => {}
+        }
+    }
+}
+
+// where a shrunk character would be back at full size, growing up from its feet: (x, y, width, height).
+fn grown_bound(character: &Character) -> (i32, i32, u8, u8) {
+    let i = character.current_sprite_i as usize;
+    let small = character.sprite.frames[i];
+    let full = spritesheet::Sprite::from_preset(&character.sprite_type).frames[i];
+    (
+        character.x_pos.to_int(),
+        character.y_pos.to_int() + small.height as i32 - full.height as i32,
+        full.width,
+        full.height,
+    )
+}
+
+impl ActiveEffects {
+    pub fn new() -> ActiveEffects {
+        ActiveEffects {
+            effects: Vec::new(),
+        }
+    }
+
+    // returns true if this effect wasn't running before.
+    pub fn add(self: &mut Self, effect_type: EffectTypes, frames: u16, character: &mut Character) -> bool {
+        let max_frames = effect_type.max_frames();
+        for effect in self.effects.iter_mut() {
+            if effect.effect_type == effect_type {
+                effect.frames_left = match effect_type.stacking_rule() {
+                    StackingRule::AddTime => effect.frames_left.saturating_add(frames),
+                    StackingRule::Restart => frames,
+                    StackingRule::KeepLongest => effect.frames_left.max(frames),
+                }
+                .min(max_frames);
+                effect.full_frames = effect.full_frames.max(effect.frames_left);
+                return false;
+            }
+        }
+        effect_type.on_start(character);
+        let frames_left = frames.min(max_frames);
+        self.effects.push(ActiveEffect {
+            effect_type,
+            frames_left,
+            full_frames: frames_left,
+        });
+        true
+    }
+
+    pub fn is_active(self: &Self, effect_type: EffectTypes) -> bool {
+        self.effects.iter().any(|e| e.effect_type == effect_type)
+    }
+
+    // count every effect down by a frame, running the expiry of the ones that are done.
+    // Ones that can't run out yet wait on their last frame.
+    pub fn tick(self: &mut Self, character: &mut Character, map: &GameMap) {
+        for effect in self.effects.iter_mut() {
+            if effect.frames_left == 1 && !effect.effect_type.can_expire(character, map) {
+                continue;
+            }
+            effect.frames_left = effect.frames_left.saturating_sub(1);
+            if effect.frames_left == 0 {
+                effect.effect_type.on_expire(character);
+            }
+        }
+        self.effects.retain(|e| e.frames_left > 0);
+    }
+
+    // expire everything at once, e.g. at the end of a level.
+    pub fn clear(self: &mut Self, character: &mut Character) {
+        for effect in self.effects.iter() {
+            effect.effect_type.on_expire(character);
+        }
+        self.effects.clear();
+    }
+}
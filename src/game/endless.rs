@@ -0,0 +1,102 @@
+use super::game_constants::{FINAL_LEVEL, LEVELS_PER_MOOD};
+
+// Endless mode keeps going past the last level, and every few levels after it (a tier), the rules get harsher:
+// less time at the start of each level, more shadow cats out to get you, and darker colours.
+pub fn endless_tier(difficulty_level: u32) -> u32 {
+    match difficulty_level {
+        0..=FINAL_LEVEL => 0,
+        _ => 1 + (difficulty_level - FINAL_LEVEL - 1) / LEVELS_PER_MOOD as u32,
+    }
+}
+
+// the seconds added at the start of a level, shrinking every tier (but never down to nothing).
+pub fn time_bonus(base: u32, tier: u32) -> u32 {
+    base.saturating_sub(tier).max(1)
+}
+
+pub const MAX_N_ENEMIES: usize = 6;
+
+pub fn n_enemies(tier: u32) -> usize {
+    (2 * tier as usize).min(MAX_N_ENEMIES)
+}
+
+// each colour a bit closer to black every tier, down to a limit.
+pub fn darkened_palette(palette: [u32; 4], tier: u32) -> [u32; 4] {
+    const DARKEN_PER_TIER: f32 = 0.12;
+    const DARKEST: f32 = 0.4;
+    let keep = (1.0 - DARKEN_PER_TIER * tier as f32).max(DARKEST);
+    palette.map(|color| {
+        let mut out = 0;
+        for shift in [0, 8, 16] {
+            let channel = (color >> shift) & 0xff;
+            out |= ((channel as f32 * keep) as u32) << shift;
+        }
+        out
+    })
+}
+
+// What endless mode keeps track of while running.
+pub struct EndlessRun {
+    // frames until each player can get hit by a shadow cat again
+    pub hit_cooldowns: [u8; 4],
+    // where the run landed on the leaderboard, once it's over
+    pub place: Option<usize>,
+}
+
+impl EndlessRun {
+    pub fn new() -> EndlessRun {
+        EndlessRun {
+            hit_cooldowns: [0; 4],
+            place: None,
+        }
+    }
+
+    // whether a shadow cat touching the player counts. Touching again right away doesn't.
+    pub fn try_hit(self: &mut Self, player_i: usize) -> bool {
+        const HIT_COOLDOWN: u8 = 90;
+        match self.hit_cooldowns[player_i] {
+            0 => {
+                self.hit_cooldowns[player_i] = HIT_COOLDOWN;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn tick(self: &mut Self) {
+        for cooldown in self.hit_cooldowns.iter_mut() {
+            *cooldown = cooldown.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_get_harsher_past_the_last_level() {
+        assert_eq!(endless_tier(FINAL_LEVEL), 0);
+        assert_eq!(endless_tier(FINAL_LEVEL + 1), 1);
+        assert_eq!(endless_tier(FINAL_LEVEL + LEVELS_PER_MOOD as u32 + 1), 2);
+
+        assert_eq!(time_bonus(8, 0), 8);
+        assert_eq!(time_bonus(8, 3), 5);
+        assert_eq!(time_bonus(8, 20), 1);
+        assert_eq!(n_enemies(0), 0);
+        assert_eq!(n_enemies(100), MAX_N_ENEMIES);
+
+        let palette = [0xffffff, 0x808080, 0x000000, 0x64c832];
+        assert_eq!(darkened_palette(palette, 0), palette);
+        assert_eq!(darkened_palette(palette, 5)[1], 0x333333);
+        assert_eq!(darkened_palette(palette, 100)[0], darkened_palette(palette, 50)[0]);
+
+        let mut endless = EndlessRun::new();
+        assert!(endless.try_hit(1));
+        assert!(!endless.try_hit(1));
+        for _ in 0..90 {
+            endless.tick();
+        }
+        assert!(endless.try_hit(1));
+    }
+}
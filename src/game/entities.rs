@@ -1,10 +1,27 @@
 use crate::spritesheet::{self, PresetSprites};
 
-use super::ability_cards::AbilityCardStack;
+use super::{ability_cards::AbilityCardStack, effects::ActiveEffects, fixed::Fixed, player_stats::PlayerStats};
 
 pub struct Player {
     pub character: Character,
     pub card_stack: AbilityCardStack,
+    pub keys: u8,
+    pub effects: ActiveEffects,
+    pub stats: PlayerStats,
+}
+
+impl Player {
+    pub fn new() -> Player {
+        let mut character = Character::new(PresetSprites::MainCat);
+        character.movement = MovementProfile::PLAYER;
+        Player {
+            character,
+            card_stack: AbilityCardStack { cards: Vec::new() },
+            keys: 0,
+            effects: ActiveEffects::new(),
+            stats: PlayerStats::new(),
+        }
+    }
 }
 
 pub enum OptionallyEnabledPlayer {
@@ -32,13 +49,55 @@ pub enum WarpAbility {
     CanWarp(WarpState)
 }
 
+// How forgiving and how floaty jumping is. Frame counts of 0 turn that feature off.
+#[derive(Clone, Copy)]
+pub struct MovementProfile {
+    // upward velocity of a jump
+    pub hop_v: Fixed,
+    // frames after walking off a ledge that a jump is still allowed
+    pub coyote_frames: u8,
+    // frames before landing that a jump press is remembered for
+    pub jump_buffer_frames: u8,
+    // letting go of jump early caps the upward velocity to this, for short hops
+    pub short_hop_v: Option<Fixed>,
+    // frames after kicking off a wall that steering is ignored, so the kick carries
+    pub wall_kick_frames: u8,
+    // hang onto the top of a wall that's within reach, and pull up onto it
+    pub ledge_grab: bool,
+    // hang onto ceilings without holding a direction, and crawl along them
+    pub ceiling_crawl: bool,
+}
+
+impl MovementProfile {
+    // how every character moved originally. The NPC AI is tuned for this.
+    pub const CLASSIC: MovementProfile = MovementProfile {
+        hop_v: Fixed::from_int(-5),
+        coyote_frames: 0,
+        jump_buffer_frames: 0,
+        short_hop_v: None,
+        wall_kick_frames: 0,
+        ledge_grab: false,
+        ceiling_crawl: false,
+    };
+
+    pub const PLAYER: MovementProfile = MovementProfile {
+        hop_v: Fixed::from_int(-5),
+        coyote_frames: 6,
+        jump_buffer_frames: 6,
+        short_hop_v: Some(Fixed::from_ratio(5, 2)),
+        wall_kick_frames: 8,
+        ledge_grab: true,
+        ceiling_crawl: true,
+    };
+}
+
 pub struct Character {
-    pub x_pos: f32,
-    pub y_pos: f32,
-    pub x_vel: f32,
-    pub y_vel: f32,
-    pub x_vel_cap: f32,
-    pub y_vel_cap: f32,
+    pub x_pos: Fixed,
+    pub y_pos: Fixed,
+    pub x_vel: Fixed,
+    pub y_vel: Fixed,
+    pub x_vel_cap: Fixed,
+    pub y_vel_cap: Fixed,
     pub count: i32,
     pub is_facing_right: bool,
     pub state: KittyStates,
@@ -48,6 +107,21 @@ pub struct Character {
     pub can_fly: bool,
     pub sprite_type: PresetSprites,
     pub warp_ability: WarpAbility,
+    pub can_double_jump: bool,
+    pub used_double_jump: bool,
+    pub last_input: u8,
+    // where the character last stood on solid ground, to come back to if they end up outside the map.
+    pub last_safe_x_pos: Fixed,
+    pub last_safe_y_pos: Fixed,
+    // the warp pad last warped to, while down is still held.
+    pub warp_pad_i: Option<usize>,
+    pub movement: MovementProfile,
+    // frames left to still jump after walking off a ledge
+    pub coyote_timer: u8,
+    // frames left that a jump pressed in the air will happen on landing
+    pub jump_buffer_timer: u8,
+    // frames left of ignoring steering after a wall kick
+    pub wall_kick_timer: u8,
 }
 
 #[derive(PartialEq, Eq, Hash)]
@@ -58,7 +132,10 @@ pub enum KittyStates {
     Walking(u8),
     JumpingUp(u8),
     HuggingWall(bool),
-    OnCeiling(u8)
+    OnCeiling(u8),
+    GrabbingLedge(u8),
+    PullingUp(u8),
+    Swimming(u8),
 }
 
 
@@ -66,12 +143,12 @@ pub enum KittyStates {
 impl Character {
     pub fn new(sprite_type: PresetSprites) -> Character {
         Character {
-            x_pos: 10 as f32,
-            y_pos: 10.0,
-            x_vel: 0.0,
-            y_vel: 0.0,
-            x_vel_cap: 2.0,
-            y_vel_cap: 7.0,
+            x_pos: Fixed::from_int(10),
+            y_pos: Fixed::from_int(10),
+            x_vel: Fixed::ZERO,
+            y_vel: Fixed::ZERO,
+            x_vel_cap: Fixed::from_int(2),
+            y_vel_cap: Fixed::from_int(7),
             count: 0,
             is_facing_right: true,
             state: KittyStates::JumpingUp(200),
@@ -80,7 +157,17 @@ impl Character {
             following_i: None,
             can_fly: false,
             sprite_type,
-            warp_ability: WarpAbility::CannotWarp
+            warp_ability: WarpAbility::CannotWarp,
+            can_double_jump: false,
+            used_double_jump: false,
+            last_input: 0,
+            last_safe_x_pos: Fixed::from_int(10),
+            last_safe_y_pos: Fixed::from_int(10),
+            warp_pad_i: None,
+            movement: MovementProfile::CLASSIC,
+            coyote_timer: 0,
+            jump_buffer_timer: 0,
+            wall_kick_timer: 0,
         }
     }
 }
\ No newline at end of file
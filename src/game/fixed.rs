@@ -0,0 +1,85 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Fixed-point number (16 integer bits, 16 fractional bits) for the simulation state,
+// so every runtime steps the game exactly the same way. Floats are only for drawing.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Fixed(pub i32);
+
+const FRAC_BITS: u32 = 16;
+const ONE: i32 = 1 << FRAC_BITS;
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+
+    pub const fn from_int(n: i32) -> Fixed {
+        Fixed(n << FRAC_BITS)
+    }
+
+    // for constants, e.g. 0.85 is from_ratio(85, 100).
+    pub const fn from_ratio(num: i32, den: i32) -> Fixed {
+        Fixed((((num as i64) << FRAC_BITS) / den as i64) as i32)
+    }
+
+    // rounds towards zero, the same as casting a float to an int.
+    pub const fn to_int(self: Self) -> i32 {
+        self.0 / ONE
+    }
+
+    pub fn to_f32(self: Self) -> f32 {
+        self.0 as f32 / ONE as f32
+    }
+
+    pub fn abs(self: Self) -> Fixed {
+        Fixed(self.0.abs())
+    }
+
+    pub fn clamp(self: Self, min: Fixed, max: Fixed) -> Fixed {
+        Fixed(self.0.clamp(min.0, max.0))
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, other: Fixed) -> Fixed {
+        Fixed(((self.0 as i64 * other.0 as i64) >> FRAC_BITS) as i32)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(-self.0)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, other: Fixed) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Fixed) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fixed {
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = *self * other;
+    }
+}
@@ -13,62 +13,80 @@ pub struct MapGenSetting {
     pub chunk_max_side_len: usize,
     pub max_n_tiles_per_chunk: usize,
     pub linear_mapsize_mult: f32,
+    // how much of the inside of each chunk is underwater, from the bottom up.
+    pub flooded_fraction: f32,
 }
 
-pub const MAP_GEN_SETTINGS: [MapGenSetting; 9] = [
+pub const MAP_GEN_SETTINGS: [MapGenSetting; 10] = [
     MapGenSetting { // normal
         chunk_min_side_len: 6,
         chunk_max_side_len: 50,
         max_n_tiles_per_chunk: 400,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // oblong
         chunk_min_side_len: 6,
         chunk_max_side_len: 25,
         max_n_tiles_per_chunk: 2000,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // teeny tunnels
         chunk_min_side_len: 6,
         chunk_max_side_len: 12,
         max_n_tiles_per_chunk: 800,
         linear_mapsize_mult: 0.7,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // large normal
         chunk_min_side_len: 20,
         chunk_max_side_len: 40,
         max_n_tiles_per_chunk: 1400,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // small
         chunk_min_side_len: 6,
         chunk_max_side_len: 30,
         max_n_tiles_per_chunk: 500,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // ridiculous tunnel
         chunk_min_side_len: 8,
         chunk_max_side_len: 10,
         max_n_tiles_per_chunk: 500,
         linear_mapsize_mult: 0.4,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // chaotic
         chunk_min_side_len: 5,
         chunk_max_side_len: 60,
         max_n_tiles_per_chunk: 500,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // spacey
         chunk_min_side_len: 12,
         chunk_max_side_len: 40,
         max_n_tiles_per_chunk: 2000,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
     },
     MapGenSetting{ // underworld
         chunk_min_side_len: 5,
         chunk_max_side_len: 80,
         max_n_tiles_per_chunk: 5000,
         linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.0,
+    },
+    MapGenSetting{ // flooded
+        chunk_min_side_len: 10,
+        chunk_max_side_len: 40,
+        max_n_tiles_per_chunk: 1400,
+        linear_mapsize_mult: 1.0,
+        flooded_fraction: 0.45,
     },
 ];
 
@@ -87,8 +105,12 @@ pub const TILE_HEIGHT_PX: usize = 5;
 
 pub const COUNTDOWN_TIMER_START: u32 = 60 * 60;
 
+// frames before the title screen starts listening to buttons
+pub const TIMER_INTERACTIVE_START: u32 = 100;
+
 pub const START_DIFFICULTY_LEVEL: u32 = 1;
-pub const FINAL_LEVEL: u32 = 45;
+// ten worlds of LEVELS_PER_MOOD levels, so every biome gets one
+pub const FINAL_LEVEL: u32 = 50;
 pub const LEVELS_PER_MOOD: usize = 5;
 
 pub const MAJOR_VERSION: u8 = 1;
@@ -1,8 +1,41 @@
-use super::{mapchunk::MapChunk, game_constants::MAX_N_TILES_IN_WHOLE_MAP};
+use super::{mapchunk::{MapChunk, TileAlignedBoundingBox}, game_constants::{MAX_N_TILES_IN_WHOLE_MAP, TILE_HEIGHT_PX, TILE_WIDTH_PX}};
 
 pub struct GameMap {
     pub chunks: Vec<MapChunk>,
-    pub num_tiles: usize
+    pub num_tiles: usize,
+    // chunks that can only be entered through locked doors. Decided before the chunks are added.
+    pub locked_chunk_idxs: Vec<usize>,
+    pub doors: Vec<LockedDoor>,
+    // checkpoints to warp between. The first one is always at the start, and always discovered.
+    pub warp_pads: Vec<WarpPad>,
+}
+
+// A checkpoint that warping characters can land on, once a player has touched it.
+pub struct WarpPad {
+    pub x_pos: f32,
+    pub y_pos: f32,
+    pub discovered: bool,
+}
+
+// A gated opening between two touching chunks. The bound is in tiles, and covers the edge of both chunks.
+pub struct LockedDoor {
+    pub chunk_a: usize,
+    pub chunk_b: usize,
+    pub bound: TileAlignedBoundingBox,
+    pub locked: bool,
+}
+
+// solid tile that fills a locked opening until it is unlocked.
+pub const DOOR_TILE: u8 = 9;
+
+impl LockedDoor {
+    // the chunk (of the two) that this door keeps closed off.
+    pub fn locked_chunk_i(self: &Self, locked_chunk_idxs: &Vec<usize>) -> usize {
+        match locked_chunk_idxs.contains(&self.chunk_a) {
+            true => self.chunk_a,
+            false => self.chunk_b,
+        }
+    }
 }
 
 // pub struct MapTileSet {
@@ -22,7 +55,7 @@ pub struct GameMap {
 // bottomright bottom   bottomleft left
 // topleft     middle   corrupt1   c2
 // c3          c4       c5         c6  
-pub const MAP_TILESETS: [[u8; 16]; 9] = [
+pub const MAP_TILESETS: [[u8; 16]; 10] = [
     [ // normal
         0, 20, 0, 17,
         0, 18, 0, 19,
@@ -78,6 +111,12 @@ pub const MAP_TILESETS: [[u8; 16]; 9] = [
         0, 26, 25, 27,
         29, 31, 28, 28
     ],
+    [ // flooded
+        0, 20, 27, 15,
+        29, 18, 28, 15,
+        25, 15, 31, 30,
+        32, 12, 31, 30
+    ],
 ];
 
 
@@ -95,78 +134,102 @@ impl GameMap {
     }
 
     pub fn link_chunk_to_touching_chunks(self: &mut Self, chunk: &mut MapChunk) {
-        for other_chunk in self.chunks.iter_mut() {
-
-            fn fuse_horizontal(
-                chunk: &mut MapChunk,
-                other_chunk: &mut MapChunk,
-            ) {
-                // check to see if the other chunk touches the top of the new chunk
-                if 
-                    other_chunk.bound.y + other_chunk.bound.height as i32 == chunk.bound.y &&
-                    other_chunk.bound.x + other_chunk.bound.width as i32 > chunk.bound.x &&
-                    other_chunk.bound.x < chunk.bound.x + chunk.bound.width as i32
-                {
-                    let min_x = core::cmp::max(chunk.bound.x, other_chunk.bound.x);
-                    let max_x = core::cmp::min(chunk.bound.x + chunk.bound.width as i32, other_chunk.bound.x + other_chunk.bound.width as i32);
-                    for absolute_coord_x in min_x..max_x {
-                        let rel_chunk_x = absolute_coord_x - chunk.bound.x;
-                        let rel_other_chunk_x = absolute_coord_x - other_chunk.bound.x;
-                        if rel_chunk_x > 0 && rel_chunk_x < chunk.bound.width as i32 - 1 {
-                            chunk.set_tile(rel_chunk_x as usize, 0 as usize, 0);
-                        }  
-                        if rel_other_chunk_x > 0 && rel_other_chunk_x < other_chunk.bound.width as i32 - 1 {
-                            other_chunk.set_tile(rel_other_chunk_x as usize, other_chunk.bound.height as usize - 1, 0)
-                        }
-                        if rel_other_chunk_x == 0 || rel_other_chunk_x == other_chunk.bound.width as i32 - 1 || rel_chunk_x == 0 || rel_chunk_x == chunk.bound.width as i32 - 1 {
-                            chunk.set_tile(rel_chunk_x as usize, 0 as usize, 9);
-                            other_chunk.set_tile(rel_other_chunk_x as usize, other_chunk.bound.height as usize - 1, 9)
+        // the chunk being linked is about to be pushed to the end of the chunk list.
+        let chunk_i = self.chunks.len();
+        for (other_chunk_i, other_chunk) in self.chunks.iter_mut().enumerate() {
+            // openings to or from a locked chunk get filled in with a door instead
+            let gated = self.locked_chunk_idxs.contains(&chunk_i) || self.locked_chunk_idxs.contains(&other_chunk_i);
+            let fill = match gated {
+                true => DOOR_TILE,
+                false => 0,
+            };
+
+            for opening in [
+                fuse_horizontal(chunk, other_chunk, fill),
+                fuse_horizontal(other_chunk, chunk, fill),
+                fuse_vertical(chunk, other_chunk, fill),
+                fuse_vertical(other_chunk, chunk, fill),
+            ] {
+                match opening {
+                    Some(bound) => {
+                        if gated {
+                            self.doors.push(LockedDoor {
+                                chunk_a: chunk_i,
+                                chunk_b: other_chunk_i,
+                                bound,
+                                locked: true,
+                            });
                         }
                     }
+                    None => {}
                 }
             }
+        }
+    }
 
-            fn fuse_vertical(
-                chunk: &mut MapChunk,
-                other_chunk: &mut MapChunk,
-            ) {
-                // check to see if the other chunk touches the left of the new chunk
-                if 
-                    other_chunk.bound.x + other_chunk.bound.width as i32 == chunk.bound.x &&
-                    other_chunk.bound.y + other_chunk.bound.height as i32 > chunk.bound.y &&
-                    other_chunk.bound.y < chunk.bound.y + chunk.bound.height as i32
-                {
-                    let min_y = core::cmp::max(chunk.bound.y, other_chunk.bound.y);
-                    let max_y = core::cmp::min(chunk.bound.y + chunk.bound.height as i32, other_chunk.bound.y + other_chunk.bound.height as i32);
-                    for absolute_coord_y in min_y..max_y {
-                        let rel_chunk_y = absolute_coord_y - chunk.bound.y;
-                        let rel_other_chunk_y = absolute_coord_y - other_chunk.bound.y;
-                        if rel_chunk_y > 0 && rel_chunk_y < chunk.bound.height as i32 - 1 {
-                            chunk.set_tile(0, rel_chunk_y as usize, 0);
-                        }
-                        if rel_other_chunk_y > 0 && rel_other_chunk_y < other_chunk.bound.height as i32 - 1 {
-                            other_chunk.set_tile(other_chunk.bound.width as usize - 1, rel_other_chunk_y as usize, 0)
-                        }
-                        if rel_other_chunk_y == 0 || rel_other_chunk_y == other_chunk.bound.height as i32 - 1 || rel_chunk_y == 0 || rel_chunk_y == chunk.bound.height as i32 - 1 {
-                            chunk.set_tile(0, rel_chunk_y as usize, 9);
-                            other_chunk.set_tile(other_chunk.bound.width as usize - 1, rel_other_chunk_y as usize, 9)
-                        }
-                    }
-                }
+    // true if the point is in the flooded part of any chunk.
+    pub fn is_water_at(self: &Self, abs_x: i32, abs_y: i32) -> bool {
+        for chunk in self.chunks.iter() {
+            if chunk.is_water_abs(abs_x, abs_y) {
+                return true;
             }
+        }
+        false
+    }
 
-            fuse_horizontal(chunk, other_chunk);
-            fuse_horizontal(other_chunk, chunk);
+    // remember that someone has been in whichever chunk this point is in.
+    pub fn mark_visited(self: &mut Self, abs_x: i32, abs_y: i32) {
+        let tile_x = abs_x.div_euclid(TILE_WIDTH_PX as i32);
+        let tile_y = abs_y.div_euclid(TILE_HEIGHT_PX as i32);
+        for chunk in self.chunks.iter_mut() {
+            if chunk.is_tile_idx_inside_tile_aligned_bound(tile_x - chunk.bound.x, tile_y - chunk.bound.y) {
+                chunk.visited = true;
+                return;
+            }
+        }
+    }
 
-            fuse_vertical(chunk, other_chunk);
-            fuse_vertical(other_chunk, chunk);
-            
+    // the next discovered warp pad after the given one (or the first, if none), wrapping around.
+    pub fn next_discovered_warp_pad(self: &Self, after: Option<usize>) -> Option<usize> {
+        let n_pads = self.warp_pads.len();
+        let start = match after {
+            Some(pad_i) => pad_i + 1,
+            None => 0,
+        };
+        for offset in 0..n_pads {
+            let pad_i = (start + offset) % n_pads;
+            if self.warp_pads[pad_i].discovered {
+                return Some(pad_i);
+            }
+        }
+        None
+    }
 
-            
-            
+    // one key opens every door leading into the locked chunk.
+    pub fn unlock_doors_of_chunk(self: &mut Self, chunk_i: usize) {
+        for door_i in 0..self.doors.len() {
+            let door = &self.doors[door_i];
+            if door.locked && (door.chunk_a == chunk_i || door.chunk_b == chunk_i) {
+                self.unlock_door(door_i);
+            }
         }
     }
 
+    // clear out the door tiles between the two chunks, the same way they would have been linked originally.
+    pub fn unlock_door(self: &mut Self, door_i: usize) {
+        let door = &mut self.doors[door_i];
+        door.locked = false;
+        let lo = door.chunk_a.min(door.chunk_b);
+        let hi = door.chunk_a.max(door.chunk_b);
+        let (lower_chunks, upper_chunks) = self.chunks.split_at_mut(hi);
+        let chunk = &mut lower_chunks[lo];
+        let other_chunk = &mut upper_chunks[0];
+        fuse_horizontal(chunk, other_chunk, 0);
+        fuse_horizontal(other_chunk, chunk, 0);
+        fuse_vertical(chunk, other_chunk, 0);
+        fuse_vertical(other_chunk, chunk, 0);
+    }
+
     pub fn add_chunk(self: & mut Self, mut chunk: MapChunk) {
         self.link_chunk_to_touching_chunks(&mut chunk);
         self.chunks.push(chunk);
@@ -178,9 +241,76 @@ impl GameMap {
         let map = GameMap { 
             chunks: chunks,
             num_tiles: 0,
+            locked_chunk_idxs: Vec::new(),
+            doors: Vec::new(),
+            warp_pads: Vec::new(),
         };
     
     
         map
     }
 }
+
+// open up the shared edge if the other chunk touches the top of the chunk. Returns the opening, if there was one.
+fn fuse_horizontal(
+    chunk: &mut MapChunk,
+    other_chunk: &mut MapChunk,
+    fill: u8,
+) -> Option<TileAlignedBoundingBox> {
+    if 
+        other_chunk.bound.y + other_chunk.bound.height as i32 == chunk.bound.y &&
+        other_chunk.bound.x + other_chunk.bound.width as i32 > chunk.bound.x &&
+        other_chunk.bound.x < chunk.bound.x + chunk.bound.width as i32
+    {
+        let min_x = core::cmp::max(chunk.bound.x, other_chunk.bound.x);
+        let max_x = core::cmp::min(chunk.bound.x + chunk.bound.width as i32, other_chunk.bound.x + other_chunk.bound.width as i32);
+        for absolute_coord_x in min_x..max_x {
+            let rel_chunk_x = absolute_coord_x - chunk.bound.x;
+            let rel_other_chunk_x = absolute_coord_x - other_chunk.bound.x;
+            if rel_chunk_x > 0 && rel_chunk_x < chunk.bound.width as i32 - 1 {
+                chunk.set_tile(rel_chunk_x as usize, 0 as usize, fill);
+            }  
+            if rel_other_chunk_x > 0 && rel_other_chunk_x < other_chunk.bound.width as i32 - 1 {
+                other_chunk.set_tile(rel_other_chunk_x as usize, other_chunk.bound.height as usize - 1, fill)
+            }
+            if rel_other_chunk_x == 0 || rel_other_chunk_x == other_chunk.bound.width as i32 - 1 || rel_chunk_x == 0 || rel_chunk_x == chunk.bound.width as i32 - 1 {
+                chunk.set_tile(rel_chunk_x as usize, 0 as usize, 9);
+                other_chunk.set_tile(rel_other_chunk_x as usize, other_chunk.bound.height as usize - 1, 9)
+            }
+        }
+        return Some(TileAlignedBoundingBox::init(min_x, chunk.bound.y - 1, (max_x - min_x) as usize, 2));
+    }
+    None
+}
+
+// open up the shared edge if the other chunk touches the left of the chunk. Returns the opening, if there was one.
+fn fuse_vertical(
+    chunk: &mut MapChunk,
+    other_chunk: &mut MapChunk,
+    fill: u8,
+) -> Option<TileAlignedBoundingBox> {
+    if 
+        other_chunk.bound.x + other_chunk.bound.width as i32 == chunk.bound.x &&
+        other_chunk.bound.y + other_chunk.bound.height as i32 > chunk.bound.y &&
+        other_chunk.bound.y < chunk.bound.y + chunk.bound.height as i32
+    {
+        let min_y = core::cmp::max(chunk.bound.y, other_chunk.bound.y);
+        let max_y = core::cmp::min(chunk.bound.y + chunk.bound.height as i32, other_chunk.bound.y + other_chunk.bound.height as i32);
+        for absolute_coord_y in min_y..max_y {
+            let rel_chunk_y = absolute_coord_y - chunk.bound.y;
+            let rel_other_chunk_y = absolute_coord_y - other_chunk.bound.y;
+            if rel_chunk_y > 0 && rel_chunk_y < chunk.bound.height as i32 - 1 {
+                chunk.set_tile(0, rel_chunk_y as usize, fill);
+            }
+            if rel_other_chunk_y > 0 && rel_other_chunk_y < other_chunk.bound.height as i32 - 1 {
+                other_chunk.set_tile(other_chunk.bound.width as usize - 1, rel_other_chunk_y as usize, fill)
+            }
+            if rel_other_chunk_y == 0 || rel_other_chunk_y == other_chunk.bound.height as i32 - 1 || rel_chunk_y == 0 || rel_chunk_y == chunk.bound.height as i32 - 1 {
+                chunk.set_tile(0, rel_chunk_y as usize, 9);
+                other_chunk.set_tile(other_chunk.bound.width as usize - 1, rel_other_chunk_y as usize, 9)
+            }
+        }
+        return Some(TileAlignedBoundingBox::init(chunk.bound.x - 1, min_y, 2, (max_y - min_y) as usize));
+    }
+    None
+}
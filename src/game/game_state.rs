@@ -1,22 +1,31 @@
-use super::cloud::Cloud;
-use super::entities::{Player, WarpAbility};
-use super::game_constants::{MapGenSetting, COUNTDOWN_TIMER_START, LEVELS_PER_MOOD, MAP_GEN_SETTINGS, START_DIFFICULTY_LEVEL};
-use super::menus::GameMode;
+use super::particles::ParticlePool;
+use super::sfx::SfxPlayer;
+use super::entities::Player;
+use super::game_constants::{MapGenSetting, COUNTDOWN_TIMER_START, FINAL_LEVEL, LEVELS_PER_MOOD, MAP_GEN_SETTINGS, START_DIFFICULTY_LEVEL};
+use super::menus::{GameMode, MenuTypes, NormalPlayModes};
+use super::music::{MusicParams, SongPlayer, Sting};
+use super::entities::KittyStates;
+use super::pickups::{LevelTallies, Pickup, PickupTypes, MAX_N_PICKUPS};
 use super::popup_text::PopTextRingbuffer;
 use super::rng::GameRng;
+use super::versus::VersusMatch;
+use super::save::SaveData;
+use super::daily::DailyRun;
+use super::endless::{endless_tier, n_enemies, time_bonus, EndlessRun};
 use super::{
-    camera::Camera,
+    camera::{Camera, CameraSettings, CameraStyles},
+    camera_effects::CameraEffects,
     entities::{Character, OptionallyEnabledPlayer},
     game_constants::{
         // MAX_N_TILES_IN_CHUNK, MAP_CHUNK_MAX_SIDE_LEN, MAP_CHUNK_MIN_SIDE_LEN,
         MAX_N_NPCS,
         TILE_HEIGHT_PX, TILE_WIDTH_PX,
     },
-    game_map::GameMap,
+    game_map::{GameMap, WarpPad},
     mapchunk::{MapChunk, TileAlignedBoundingBox},
     rng::Rng,
 };
-use crate::game::ability_cards::AbilityCardStack;
+use crate::game::{ability_cards::AbilityCardStack, fixed::Fixed};
 use crate::game::game_map::MAP_TILESETS;
 use crate::game::music::SONGS;
 use crate::kitty_ss;
@@ -24,11 +33,18 @@ use crate::spritesheet::{self, KITTY_SPRITESHEET_PALETTES};
 
 // Games can either be fixed-seed and timed for speedrunning, or random.
 type RunSeed = u32;
+// how many rounds a versus match can go on for
+type BestOf = u8;
 pub enum RunType {
     Casual,
     TimedMode,
     Speedrun(RunSeed),
-    Chaos
+    Chaos,
+    Versus(BestOf),
+    // timed, on the day's seed (kept in the save)
+    Daily,
+    // timed, and keeps going past the last level
+    Endless,
 }
 
 // pub enum Difficulty {
@@ -37,9 +53,34 @@ pub enum RunType {
 //     Hard
 // }
 
+// arrows at the screen edge pointing at the nearest unfound kitties.
+pub enum KittyArrows {
+    // only in casual mode
+    Auto,
+    On,
+    Off,
+}
+
 pub struct GameSettings {
     pub run_type: RunType,
     // pub difficulty: Difficulty
+    pub kitty_arrows: KittyArrows,
+    // no shaking or flashing
+    pub reduced_motion: bool,
+    pub camera_style: CameraStyles,
+}
+
+impl GameSettings {
+    pub fn show_kitty_arrows(self: &Self) -> bool {
+        match self.kitty_arrows {
+            KittyArrows::Auto => match self.run_type {
+                RunType::Casual => true,
+                _ => false,
+            },
+            KittyArrows::On => true,
+            KittyArrows::Off => false,
+        }
+    }
 }
 
 
@@ -50,7 +91,10 @@ pub struct GameState<'a> {
     pub spritesheet_stride: usize,
     pub background_tiles: &'static Vec<spritesheet::Sprite>,
     pub map: GameMap,
-    pub camera: Camera,
+    // one for each player, following them around their part of the screen
+    pub cameras: [Camera; 4],
+    // whether this is a netplay session, which never splits the screen
+    pub netplay: bool,
     pub rng: GameRng,
     pub game_mode: GameMode,
     pub countdown_timer_msec: u32,
@@ -66,19 +110,34 @@ pub struct GameState<'a> {
     pub tileset_idx: usize,
     pub map_gen_settings_idx: usize,
     pub tutorial_text_counter: u8,
-    pub clouds: Vec<Cloud>,
+    pub particles: ParticlePool,
     pub countdown_and_score_bonus: u32,
     pub settings: GameSettings,
     pub speedrun_timer_msec: u32,
+    pub pickups: Vec<Pickup>,
+    pub level_tallies: LevelTallies,
+    pub score_multiplier: u32,
+    // NPCs keep their last input on frames they aren't given a new one.
+    pub npc_inputs: [u8; MAX_N_NPCS],
+    // each player can pull up the minimap for themselves.
+    pub minimap_shown: [bool; 4],
+    pub camera_effects: CameraEffects,
+    pub sfx: SfxPlayer,
+    pub song_player: SongPlayer,
+    pub versus: VersusMatch,
+    pub save: SaveData,
+    // the save changed, and should get written out
+    pub save_dirty: bool,
+    pub daily: DailyRun,
+    pub endless: EndlessRun,
+    // shadow cats, out to take time off of players in endless mode
+    pub enemies: Vec<Character>,
 }
 
 impl GameState<'static> {
     pub fn new() -> GameState<'static> {
         let characters = [
-            OptionallyEnabledPlayer::Enabled(Player {
-                character: Character::new(spritesheet::PresetSprites::MainCat),
-                card_stack: AbilityCardStack { cards: Vec::new() },
-            }),
+            OptionallyEnabledPlayer::Enabled(Player::new()),
             OptionallyEnabledPlayer::Disabled,
             OptionallyEnabledPlayer::Disabled,
             OptionallyEnabledPlayer::Disabled,
@@ -94,12 +153,8 @@ impl GameState<'static> {
             spritesheet_stride: spritesheet::KITTY_SPRITESHEET_STRIDE as usize,
             background_tiles: spritesheet::Sprite::get_spritesheet(),
             map: GameMap::create_map(),
-            camera: Camera {
-                current_viewing_x_offset: 0.0,
-                current_viewing_y_offset: 0.0,
-                current_viewing_x_target: 0.0,
-                current_viewing_y_target: 0.0,
-            },
+            cameras: [Camera::new(CameraSettings::DEFAULT); 4],
+            netplay: false,
             rng,
             game_mode: GameMode::StartScreen,
             countdown_timer_msec: 60 * 3,
@@ -118,21 +173,74 @@ impl GameState<'static> {
             tileset_idx: 0,
             map_gen_settings_idx: 0,
             tutorial_text_counter: 0,
-            clouds: Vec::new(),
+            particles: ParticlePool::new(),
             countdown_and_score_bonus: 0,
             settings: GameSettings{
                 run_type: RunType::Casual,
                 // difficulty: Difficulty::Medium
+                kitty_arrows: KittyArrows::Auto,
+                reduced_motion: false,
+                camera_style: CameraStyles::Smart,
             },
             speedrun_timer_msec: 0,
+            pickups: Vec::new(),
+            level_tallies: LevelTallies::new(),
+            score_multiplier: 1,
+            npc_inputs: [0; MAX_N_NPCS],
+            minimap_shown: [false; 4],
+            camera_effects: CameraEffects::new(),
+            sfx: SfxPlayer::new(),
+            song_player: SongPlayer::new(),
+            versus: VersusMatch::new(),
+            save: SaveData::new(),
+            save_dirty: false,
+            daily: DailyRun::new(),
+            endless: EndlessRun::new(),
+            enemies: Vec::new(),
         }
     }
 
+    // how the music should go along with what's happening, for whoever's watching.
+    pub fn music_params(self: &Self, player_idx: usize) -> MusicParams {
+        let mut params = MusicParams::PLAIN;
+        match &self.game_mode {
+            GameMode::NormalPlay(NormalPlayModes::HoverModal(m)) => {
+                params.sting = match m.menu_type {
+                    MenuTypes::WonLevel | MenuTypes::WonGame | MenuTypes::MatchOver => Some(Sting::Victory),
+                    MenuTypes::Done => Some(Sting::Fail),
+                    _ => None,
+                };
+            }
+            GameMode::NormalPlay(NormalPlayModes::MainGameplay) => {
+                // drums come in as time runs out, when running out matters
+                if let RunType::TimedMode | RunType::Daily | RunType::Endless = self.settings.run_type {
+                    params.percussion = match self.countdown_timer_msec / 60 {
+                        0..=9 => 3,
+                        10..=19 => 2,
+                        20..=29 => 1,
+                        _ => 0,
+                    };
+                }
+                // and things pick up while flying
+                const FLYING_TEMPO: f32 = 1.25;
+                if let OptionallyEnabledPlayer::Enabled(p) = &self.players[player_idx] {
+                    if let (true, KittyStates::JumpingUp(_)) = (p.character.can_fly, &p.character.state) {
+                        params.tempo = FLYING_TEMPO;
+                    }
+                }
+            }
+            _ => {}
+        }
+        params
+    }
+
     pub fn regenerate_map(self: &mut Self) {
         self.godmode = false;
+        self.particles.clear();
 
 
         
+        // a song for each world, from the ones after the title screen's
         let new_song_idx = 1 + ((self.difficulty_level as usize - 1) / LEVELS_PER_MOOD) % (SONGS.len() - 1);
 
         let new_pallete_idx = ((self.difficulty_level as usize - 1) / LEVELS_PER_MOOD) % KITTY_SPRITESHEET_PALETTES.len();
@@ -163,29 +271,49 @@ impl GameState<'static> {
         } else {
             1.0
         };
-        let chaotic_map = MapGenSetting{
+        let mut chaotic_map = MapGenSetting{
             chunk_min_side_len: msl,
             chunk_max_side_len: msl + max_diff,
             max_n_tiles_per_chunk: mnt,
             linear_mapsize_mult: lmm,
+            flooded_fraction: 0.0,
         };
         match self.settings.run_type {
             RunType::Chaos => {
+                // sometimes chaos is soggy
+                const CHAOS_FLOOD_CHANCE: u64 = 4;
+                if self.rng.next_for_worldgen() % CHAOS_FLOOD_CHANCE == 0 {
+                    chaotic_map.flooded_fraction = MAP_GEN_SETTINGS[MAP_GEN_SETTINGS.len() - 1].flooded_fraction;
+                }
                 map_gen_setting = &chaotic_map;
                 self.tileset_idx = self.rng.next_for_worldgen() as usize % MAP_TILESETS.len();
                 self.pallette_idx = self.rng.next_for_worldgen() as usize % KITTY_SPRITESHEET_PALETTES.len();
                 self.song_idx = 1 + (self.rng.next_for_worldgen() as usize) % (SONGS.len() - 1);
             },
+            // past the last level, the moods get mixed up instead of starting over
+            RunType::Endless if endless_tier(self.difficulty_level) > 0 => {
+                self.map_gen_settings_idx = self.rng.next_for_worldgen() as usize % MAP_GEN_SETTINGS.len();
+                map_gen_setting = &MAP_GEN_SETTINGS[self.map_gen_settings_idx];
+                self.tileset_idx = self.rng.next_for_worldgen() as usize % MAP_TILESETS.len();
+                self.pallette_idx = self.rng.next_for_worldgen() as usize % KITTY_SPRITESHEET_PALETTES.len();
+                self.song_idx = 1 + (self.rng.next_for_worldgen() as usize) % (SONGS.len() - 1);
+            },
             _ => {}
         }
+        let endless_tier = match self.settings.run_type {
+            RunType::Endless => endless_tier(self.difficulty_level),
+            _ => 0,
+        };
         let map_chunk_min_side_len = map_gen_setting.chunk_min_side_len;
         let map_chunk_max_side_len = map_gen_setting.chunk_max_side_len;
         let max_n_tiles_in_chunk = map_gen_setting.max_n_tiles_per_chunk;
+        let flooded_fraction = map_gen_setting.flooded_fraction;
         
         // an average-sized map is ~ 30x30 = 900 blocks. Anything smaller is more twisty and denser. Make those
         // twistier maps smaller by a linear factor.
 
-        let max_n_tiles_in_map: u32 = (0.7 * 2048.0) as u32 + (map_gen_setting.linear_mapsize_mult * 0.25 * 2048.0) as u32 * self.difficulty_level;
+        // (maps stop growing after the last level, or endless mode would run out of memory)
+        let max_n_tiles_in_map: u32 = (0.7 * 2048.0) as u32 + (map_gen_setting.linear_mapsize_mult * 0.25 * 2048.0) as u32 * self.difficulty_level.min(FINAL_LEVEL);
 
 
         let map = &mut self.map;
@@ -196,9 +324,14 @@ impl GameState<'static> {
         for optional_player in self.players.iter_mut() {
             match optional_player {
                 OptionallyEnabledPlayer::Enabled(p) => {
-                    p.character.x_pos = 10.0;
-                    p.character.y_pos = 10.0;
-                    p.character.can_fly = false;
+                    p.character.x_pos = Fixed::from_int(10);
+                    p.character.y_pos = Fixed::from_int(10);
+                    p.character.last_safe_x_pos = Fixed::from_int(10);
+                    p.character.last_safe_y_pos = Fixed::from_int(10);
+                    p.character.warp_pad_i = None;
+                    p.keys = 0;
+                    // card powers only last until the end of the level
+                    p.effects.clear(&mut p.character);
                     if self.difficulty_level == START_DIFFICULTY_LEVEL {
                         p.card_stack = AbilityCardStack{
                             cards: Vec::new()
@@ -216,7 +349,7 @@ impl GameState<'static> {
         self.total_npcs_to_find =
             (1 + (self.difficulty_level / 3) + rng.next_for_worldgen() as u32 % 3).min(MAX_N_NPCS as u32);
 
-        self.countdown_and_score_bonus = 4 + self.difficulty_level.min(20) / 3;
+        self.countdown_and_score_bonus = time_bonus(4 + self.difficulty_level.min(20) / 3, endless_tier);
 
         self.countdown_timer_msec += self.countdown_and_score_bonus * 60;
         self.countdown_timer_msec = self.countdown_timer_msec.min(100 * 60 - 1);
@@ -228,16 +361,6 @@ impl GameState<'static> {
                 self.score = 0;
                 self.tutorial_text_counter = 0;
                 self.speedrun_timer_msec = 0;
-
-                // Reset warping ability only on a new game. Keep the ability each level.
-                for optional_player in self.players.iter_mut() {
-                    match optional_player {
-                        OptionallyEnabledPlayer::Enabled(p) => {
-                            p.character.warp_ability = WarpAbility::CannotWarp;
-                        }
-                        OptionallyEnabledPlayer::Disabled => {}
-                    }
-                }
             }
             _ => {}
         }
@@ -419,6 +542,72 @@ impl GameState<'static> {
             }
         }
 
+        // choose chunks to lock behind doors. The unlocked chunks must stay connected to the origin
+        // chunk, and every locked chunk must border an unlocked one, so that the keys (which only go
+        // in unlocked chunks) can always be reached before their doors.
+        fn can_lock_chunk(
+            bounds: &Vec<TileAlignedBoundingBox>,
+            locked_chunk_idxs: &Vec<usize>,
+            candidate_i: usize,
+        ) -> bool {
+            if candidate_i == 0 || locked_chunk_idxs.contains(&candidate_i) {
+                return false;
+            }
+            let mut has_unlocked_neighbor = false;
+            for (i, bound) in bounds.iter().enumerate() {
+                if i != candidate_i && bound.touches(&bounds[candidate_i]) {
+                    if locked_chunk_idxs.contains(&i) {
+                        // don't let locked chunks lead into each other
+                        return false;
+                    }
+                    has_unlocked_neighbor = true;
+                }
+            }
+            if !has_unlocked_neighbor {
+                return false;
+            }
+
+            // flood fill from the origin chunk without going through locked chunks
+            let mut reached: Vec<bool> = bounds.iter().map(|_| false).collect();
+            let mut to_visit: Vec<usize> = Vec::new();
+            reached[0] = true;
+            to_visit.push(0);
+            while let Some(i) = to_visit.pop() {
+                for (j, bound) in bounds.iter().enumerate() {
+                    if !reached[j] && j != candidate_i && !locked_chunk_idxs.contains(&j) && bound.touches(&bounds[i]) {
+                        reached[j] = true;
+                        to_visit.push(j);
+                    }
+                }
+            }
+            for (i, was_reached) in reached.iter().enumerate() {
+                if !was_reached && i != candidate_i && !locked_chunk_idxs.contains(&i) {
+                    return false;
+                }
+            }
+            true
+        }
+
+        map.locked_chunk_idxs.clear();
+        map.doors.clear();
+        const MAX_N_LOCKED_CHUNKS: u32 = 2;
+        const LOCKED_CHUNK_ATTEMPTS: u32 = 10;
+        let n_locked_chunks = match self.difficulty_level {
+            0..=2 => 0,
+            _ => (rng.next_for_worldgen() as u32 % 2 + self.difficulty_level / 15).min(MAX_N_LOCKED_CHUNKS),
+        };
+        if current_chunk_locations.len() > 2 {
+            for _ in 0..n_locked_chunks {
+                for _ in 0..LOCKED_CHUNK_ATTEMPTS {
+                    let candidate_i = rng.next_for_worldgen() as usize % current_chunk_locations.len();
+                    if can_lock_chunk(&current_chunk_locations, &map.locked_chunk_idxs, candidate_i) {
+                        map.locked_chunk_idxs.push(candidate_i);
+                        break;
+                    }
+                }
+            }
+        }
+
         'init_the_chunks: for current_chunk_location in current_chunk_locations.into_iter() {
             let mut chunk = MapChunk::init();
 
@@ -468,6 +657,12 @@ impl GameState<'static> {
             chunk.set_tile(chunk.bound.width as usize - 1, 0, 2);
             chunk.set_tile(0, chunk.bound.height as usize - 1, 6);
 
+            // flood the bottom of the chunk
+            let n_flooded_rows = ((chunk.bound.height - 2) as f32 * flooded_fraction) as usize;
+            if n_flooded_rows > 0 {
+                chunk.water_top_row = Some(chunk.bound.height - 1 - n_flooded_rows);
+            }
+
             map.add_chunk(chunk);
         }
 
@@ -475,13 +670,199 @@ impl GameState<'static> {
         for i in 0..npcs.len() {
             let rand_chunk_i = rng.next_for_worldgen() as usize % (map.chunks.len() - 1) + 1;
             let chunk: &MapChunk = &map.chunks[rand_chunk_i];
-            npcs[i].x_pos = chunk.bound.x as f32 * TILE_WIDTH_PX as f32 + 10.0;
-            npcs[i].y_pos = chunk.bound.y as f32 * TILE_HEIGHT_PX as f32 + 10.0;
+            npcs[i].x_pos = Fixed::from_int(chunk.bound.x * TILE_WIDTH_PX as i32 + 10);
+            npcs[i].y_pos = Fixed::from_int(chunk.bound.y * TILE_HEIGHT_PX as i32 + 10);
+            npcs[i].last_safe_x_pos = npcs[i].x_pos;
+            npcs[i].last_safe_y_pos = npcs[i].y_pos;
         }
 
         // reset NPCs
         for npc in npcs.iter_mut() {
             npc.following_i = None;
         }
+
+        // shadow cats, more of them every endless tier (also not in the origin chunk)
+        self.enemies.clear();
+        for _ in 0..n_enemies(endless_tier) {
+            let rand_chunk_i = rng.next_for_worldgen() as usize % (map.chunks.len() - 1) + 1;
+            let chunk: &MapChunk = &map.chunks[rand_chunk_i];
+            let mut enemy = Character::new(spritesheet::PresetSprites::Kitty4);
+            enemy.x_pos = Fixed::from_int(chunk.bound.x * TILE_WIDTH_PX as i32 + 10);
+            enemy.y_pos = Fixed::from_int(chunk.bound.y * TILE_HEIGHT_PX as i32 + 10);
+            enemy.last_safe_x_pos = enemy.x_pos;
+            enemy.last_safe_y_pos = enemy.y_pos;
+            self.enemies.push(enemy);
+        }
+
+        // spawn pickups (also not in origin chunk), anywhere inside the chunk walls
+        self.pickups.clear();
+        self.level_tallies = LevelTallies::new();
+        self.score_multiplier = 1;
+        let n_pickups = (1 + self.difficulty_level / 4 + rng.next_for_worldgen() as u32 % 2).min(MAX_N_PICKUPS as u32);
+        for _ in 0..n_pickups {
+            let rand_chunk_i = rng.next_for_worldgen() as usize % (map.chunks.len() - 1) + 1;
+            let chunk: &MapChunk = &map.chunks[rand_chunk_i];
+            let rel_x = 1 + rng.next_for_worldgen() as usize % (chunk.bound.width - 3);
+            let rel_y = 1 + rng.next_for_worldgen() as usize % (chunk.bound.height - 3);
+            let pickup_type = Pickup::random_type(rng.next_for_worldgen());
+            self.pickups.push(Pickup::new(
+                pickup_type,
+                (chunk.bound.x + rel_x as i32) as f32 * TILE_WIDTH_PX as f32,
+                (chunk.bound.y + rel_y as i32) as f32 * TILE_HEIGHT_PX as f32,
+            ));
+        }
+
+        // warp pads: one at the start, and a few more sitting on the floor of other chunks
+        fn find_floor_row(chunk: &MapChunk, col: usize) -> Option<usize> {
+            for row in 1..chunk.bound.height - 1 {
+                if chunk.get_tile(col, row) == 0 && chunk.get_tile(col + 1, row) == 0 && chunk.get_tile(col, row + 1) != 0 {
+                    return Some(row);
+                }
+            }
+            None
+        }
+        const MAX_N_EXTRA_WARP_PADS: usize = 3;
+        map.warp_pads.clear();
+        let n_extra_warp_pads = ((map.chunks.len() - 1) / 3).min(MAX_N_EXTRA_WARP_PADS);
+        for pad_i in 0..n_extra_warp_pads + 1 {
+            let (chunk_i, col) = match pad_i {
+                // right under where the players start
+                0 => (0, 10 / TILE_WIDTH_PX),
+                _ => {
+                    let rand_chunk_i = rng.next_for_worldgen() as usize % (map.chunks.len() - 1) + 1;
+                    let chunk_w = map.chunks[rand_chunk_i].bound.width;
+                    (rand_chunk_i, 1 + rng.next_for_worldgen() as usize % (chunk_w - 3))
+                }
+            };
+            let chunk: &MapChunk = &map.chunks[chunk_i];
+            match find_floor_row(chunk, col) {
+                Some(row) => {
+                    const WARP_PAD_HEIGHT_PX: f32 = 3.0;
+                    map.warp_pads.push(WarpPad {
+                        x_pos: (chunk.bound.x + col as i32) as f32 * TILE_WIDTH_PX as f32,
+                        y_pos: (chunk.bound.y + row as i32 + 1) as f32 * TILE_HEIGHT_PX as f32 - WARP_PAD_HEIGHT_PX,
+                        discovered: pad_i == 0,
+                    });
+                }
+                None => {}
+            }
+        }
+
+        // one key per locked chunk, hidden somewhere that isn't locked
+        for _ in 0..map.locked_chunk_idxs.len() {
+            let mut key_chunk_i = 0;
+            for _ in 0..map.chunks.len() {
+                let rand_chunk_i = rng.next_for_worldgen() as usize % map.chunks.len();
+                if !map.locked_chunk_idxs.contains(&rand_chunk_i) {
+                    key_chunk_i = rand_chunk_i;
+                    break;
+                }
+            }
+            let chunk: &MapChunk = &map.chunks[key_chunk_i];
+            let rel_x = 1 + rng.next_for_worldgen() as usize % (chunk.bound.width - 3);
+            let rel_y = 1 + rng.next_for_worldgen() as usize % (chunk.bound.height - 3);
+            self.pickups.push(Pickup::new(
+                PickupTypes::Key,
+                (chunk.bound.x + rel_x as i32) as f32 * TILE_WIDTH_PX as f32,
+                (chunk.bound.y + rel_y as i32) as f32 * TILE_HEIGHT_PX as f32,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::collision::{check_entity_collisions, update_pos};
+    use crate::game::entities::MovingEntity;
+    use crate::game::rng::Rng;
+    use crate::wasm4::{BUTTON_1, BUTTON_DOWN, BUTTON_LEFT, BUTTON_RIGHT};
+
+    // play a level with scripted inputs, hashing every character's position and velocity each frame,
+    // and where the camera (which cards fly in from) is looking.
+    fn hash_of_scripted_run(n_frames: u32) -> u64 {
+        spritesheet::Sprite::init_all_sprites_for_tests();
+
+        let mut game_state = GameState::new();
+        game_state.rng = GameRng::FixedSeed(Rng::new_from_seed(1234), Rng::new_from_seed(1234));
+        game_state.difficulty_level = 7;
+        game_state.regenerate_map();
+
+        // FNV-1a
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut mix = |n: i32| {
+            for b in n.to_le_bytes() {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+
+        for frame in 0..n_frames {
+            let player_input = match (frame / 90) % 4 {
+                0 => BUTTON_RIGHT,
+                1 => BUTTON_RIGHT | BUTTON_1,
+                2 => BUTTON_LEFT,
+                _ => BUTTON_LEFT | BUTTON_1 | BUTTON_DOWN,
+            };
+            update_pos(&game_state.map, MovingEntity::OptionalPlayer(&mut game_state.players[0]), player_input, false, &mut game_state.particles, None);
+            for npc in game_state.npcs.iter_mut() {
+                let npc_input = match game_state.rng.next_for_input() % 8 {
+                    0 => BUTTON_LEFT,
+                    1 => BUTTON_RIGHT,
+                    2 => BUTTON_1,
+                    _ => 0,
+                };
+                update_pos(&game_state.map, MovingEntity::NPC(npc), npc_input, false, &mut game_state.particles, None);
+            }
+            check_entity_collisions(&mut game_state);
+            game_state.update_cameras();
+
+            let camera = &game_state.cameras[0];
+            for f in [camera.current_viewing_x_offset, camera.current_viewing_y_offset, camera.look_ahead_x, camera.focus_y] {
+                mix(f.to_bits() as i32);
+            }
+            if let OptionallyEnabledPlayer::Enabled(p) = &game_state.players[0] {
+                for c in [&p.character].into_iter().chain(game_state.npcs.iter()) {
+                    mix(c.x_pos.0);
+                    mix(c.y_pos.0);
+                    mix(c.x_vel.0);
+                    mix(c.y_vel.0);
+                }
+                for card in p.card_stack.cards.iter().flatten() {
+                    mix(card.x_pos.0);
+                    mix(card.y_pos.0);
+                }
+            }
+        }
+        hash
+    }
+
+    #[test]
+    fn campaign_visits_every_biome_and_song() {
+        spritesheet::Sprite::init_all_sprites_for_tests();
+        let mut game_state = GameState::new();
+        let mut seen = vec![];
+        let mut songs_played = vec![];
+        for level in (START_DIFFICULTY_LEVEL..=FINAL_LEVEL).step_by(LEVELS_PER_MOOD) {
+            game_state.difficulty_level = level;
+            game_state.regenerate_map();
+            seen.push((game_state.map_gen_settings_idx, game_state.tileset_idx, game_state.pallette_idx));
+            songs_played.push(game_state.song_idx);
+        }
+        for i in 0..MAP_GEN_SETTINGS.len() {
+            assert!(seen.contains(&(i, i, i)), "world {} never comes up", i + 1);
+        }
+        // every song but the title screen's
+        for i in 1..SONGS.len() {
+            assert!(songs_played.contains(&i), "song {} never plays", i);
+        }
+    }
+
+    #[test]
+    fn long_run_matches_golden_hash() {
+        const GOLDEN_HASH: u64 = 0x3434a1db1613dfe8;
+        let hash = hash_of_scripted_run(60 * 60);
+        assert_eq!(hash, hash_of_scripted_run(60 * 60));
+        assert_eq!(hash, GOLDEN_HASH, "simulation changed: got {:#x}", hash);
     }
 }
@@ -13,11 +13,23 @@ impl TileAlignedBoundingBox {
     pub fn init(x: i32, y: i32, w: usize, h: usize) -> Self {
         return TileAlignedBoundingBox { x:x, y: y, width: w, height: h }
     }
+
+    // true if the two bounds share part of an edge (and so would get linked together).
+    pub fn touches(self: &Self, other: &TileAlignedBoundingBox) -> bool {
+        let x_overlap = self.x + self.width as i32 > other.x && self.x < other.x + other.width as i32;
+        let y_overlap = self.y + self.height as i32 > other.y && self.y < other.y + other.height as i32;
+        (x_overlap && (self.y + self.height as i32 == other.y || other.y + other.height as i32 == self.y))
+            || (y_overlap && (self.x + self.width as i32 == other.x || other.x + other.width as i32 == self.x))
+    }
 }
 
 pub struct MapChunk {
     pub tiles: Vec<u8>,
-    pub bound: TileAlignedBoundingBox
+    pub bound: TileAlignedBoundingBox,
+    // every empty tile on this row (relative to the chunk) and below it is underwater.
+    pub water_top_row: Option<usize>,
+    // a player has been inside this chunk, so it shows up filled in on the minimap.
+    pub visited: bool,
 }
 
 pub enum OutOfChunkBound {
@@ -34,7 +46,9 @@ impl MapChunk {
                 x: 1,
                 width: 1,
                 height: 1,
-            }
+            },
+            water_top_row: None,
+            visited: false,
         };
 
         chunk
@@ -123,6 +137,21 @@ impl MapChunk {
         return Result::Err(OutOfChunkBound::OUT);
     }
 
+    pub fn is_water_abs(self: &Self, abs_x: i32, abs_y: i32) -> bool {
+        let water_top_row = match self.water_top_row {
+            Some(row) => row as i32,
+            None => return false,
+        };
+        // rounding down, so points just outside the chunk don't count as its edge tiles
+        let rel_x = abs_x.div_euclid(TILE_WIDTH_PX as i32) - self.bound.x;
+        let rel_y = abs_y.div_euclid(TILE_HEIGHT_PX as i32) - self.bound.y;
+
+        if self.is_tile_idx_inside_tile_aligned_bound(rel_x, rel_y) && rel_y >= water_top_row {
+            return self.get_tile(rel_x as usize, rel_y as usize) == 0;
+        }
+        false
+    }
+
     pub fn initialize(self: &mut Self) -> bool {
         self.tiles.clear();
         let n_bytes_for_chunk_storage = (self.bound.width * self.bound.height) / 2 + 2;
@@ -6,7 +6,9 @@ pub enum MenuTypes {
     // Options,
     WonLevel,
     Done,
-    WonGame
+    WonGame,
+    // the final standings of a versus match
+    MatchOver,
 }
 
 pub struct Modal {
@@ -27,6 +29,32 @@ impl Modal {
             menu_type
         }
     }
+
+    // slide and grow towards the target position, bobbing up and down a little.
+    pub fn update_position(self: &mut Self, song_timer: u32) {
+        const SPEED: f32 = 0.15;
+
+        let actual_position = &mut self.actual_position;
+        let target_position = &self.target_position;
+        let real_tpy = target_position.y + (4f32 * num::Float::sin(song_timer as f32 * 0.05f32)) as i32;
+
+        actual_position.x += (target_position.x as f32 - actual_position.x) * SPEED;
+        actual_position.y += (real_tpy as f32 - actual_position.y) * SPEED;
+        actual_position.width += (target_position.width as f32 - actual_position.width) * SPEED;
+        actual_position.height += (target_position.height as f32 - actual_position.height) * SPEED;
+    }
+
+    // once the modal is (almost) fully grown, its text can be shown.
+    pub fn ready_to_show_text(self: &Self) -> bool {
+        const TOL: f32 = 10.0;
+        (self.actual_position.width - self.target_position.width as f32).abs() < TOL
+    }
+
+    // a little after the text shows up, buttons start doing things.
+    pub fn ready_to_select(self: &Self) -> bool {
+        const INTERACTIVE_DELAY: u32 = 60;
+        self.ready_to_show_text() && self.timer >= INTERACTIVE_DELAY
+    }
 }
 
 pub enum NormalPlayModes {
@@ -38,6 +66,9 @@ pub enum NormalPlayModes {
 pub enum SelectMenuFocuses {
     // Difficulty,
     RunType,
+    Assists,
+    Motion,
+    Camera,
     // CharacterSelect,
     // StartGameBtn
 }
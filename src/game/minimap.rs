@@ -0,0 +1,93 @@
+use super::{
+    game_constants::{TILE_HEIGHT_PX, TILE_WIDTH_PX},
+    game_map::GameMap,
+    mapchunk::TileAlignedBoundingBox,
+};
+
+// Where the whole map lands when it's shrunk down to fit a box on the screen.
+pub struct MinimapLayout {
+    // top left of the map, in tiles
+    pub map_x: i32,
+    pub map_y: i32,
+    // top left of the map on the screen
+    pub screen_x: f32,
+    pub screen_y: f32,
+    // screen pixels per tile
+    pub scale: f32,
+}
+
+impl MinimapLayout {
+    // shrink the map to fit the box, keeping its shape, and center it.
+    pub fn fit(map: &GameMap, box_x: i32, box_y: i32, box_w: u32, box_h: u32) -> MinimapLayout {
+        let mut min_x = i32::MAX;
+        let mut min_y = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut max_y = i32::MIN;
+        for chunk in map.chunks.iter() {
+            min_x = min_x.min(chunk.bound.x);
+            min_y = min_y.min(chunk.bound.y);
+            max_x = max_x.max(chunk.bound.x + chunk.bound.width as i32);
+            max_y = max_y.max(chunk.bound.y + chunk.bound.height as i32);
+        }
+        if map.chunks.is_empty() {
+            (min_x, min_y, max_x, max_y) = (0, 0, 1, 1);
+        }
+        let map_w = (max_x - min_x) as f32;
+        let map_h = (max_y - min_y) as f32;
+        let scale = (box_w as f32 / map_w).min(box_h as f32 / map_h);
+
+        MinimapLayout {
+            map_x: min_x,
+            map_y: min_y,
+            screen_x: box_x as f32 + (box_w as f32 - map_w * scale) / 2.0,
+            screen_y: box_y as f32 + (box_h as f32 - map_h * scale) / 2.0,
+            scale,
+        }
+    }
+
+    // where a chunk goes on screen, as x, y, w, h. Always at least a pixel across.
+    pub fn bound_to_screen(self: &Self, bound: &TileAlignedBoundingBox) -> (i32, i32, u32, u32) {
+        let x = self.screen_x + (bound.x - self.map_x) as f32 * self.scale;
+        let y = self.screen_y + (bound.y - self.map_y) as f32 * self.scale;
+        let w = (bound.width as f32 * self.scale).max(1.0);
+        let h = (bound.height as f32 * self.scale).max(1.0);
+        (x as i32, y as i32, w as u32, h as u32)
+    }
+
+    // where a point in the world (in pixels) goes on screen.
+    pub fn world_to_screen(self: &Self, x: f32, y: f32) -> (i32, i32) {
+        let tile_x = x / TILE_WIDTH_PX as f32 - self.map_x as f32;
+        let tile_y = y / TILE_HEIGHT_PX as f32 - self.map_y as f32;
+        ((self.screen_x + tile_x * self.scale) as i32, (self.screen_y + tile_y * self.scale) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::mapchunk::MapChunk;
+
+    #[test]
+    fn whole_map_fits_in_the_box() {
+        let mut map = GameMap::create_map();
+        for (x, y, w, h) in [(0, 0, 40, 20), (40, -30, 10, 50), (-60, 5, 60, 10)] {
+            let mut chunk = MapChunk::init();
+            chunk.bound = TileAlignedBoundingBox::init(x, y, w, h);
+            chunk.initialize();
+            map.add_chunk(chunk);
+        }
+
+        let (box_x, box_y, box_w, box_h) = (10, 20, 140, 100);
+        let layout = MinimapLayout::fit(&map, box_x, box_y, box_w, box_h);
+        for chunk in map.chunks.iter() {
+            let (x, y, w, h) = layout.bound_to_screen(&chunk.bound);
+            assert!(x >= box_x && y >= box_y);
+            assert!(x + w as i32 <= box_x + box_w as i32);
+            assert!(y + h as i32 <= box_y + box_h as i32);
+        }
+        // the map is wider than it is tall, so it spans the box side to side
+        let (left, _) = layout.world_to_screen(-60.0 * TILE_WIDTH_PX as f32, 0.0);
+        let (right, _) = layout.world_to_screen(50.0 * TILE_WIDTH_PX as f32, 0.0);
+        assert!(left <= box_x + 1 && right >= box_x + box_w as i32 - 1);
+    }
+}
@@ -2,6 +2,17 @@ use crate::wasm4::*;
 
 pub struct Song {
     pub name: &'static str,
+    pub mode: SongMode,
+}
+
+pub enum SongMode {
+    // a melody made up on the fly from a scale
+    Generated(Generator),
+    // notes written out in patterns, tracker style
+    Tracked(Track),
+}
+
+pub struct Generator {
     pub scale: [u16; 8],
     pub f1_pitchchange_timer: u8,
     pub f2_pitchchange_timer: u8,
@@ -11,114 +22,447 @@ pub struct Song {
     pub time_signature: (u8, u8),
 }
 
-pub const SONGS: [Song; 10] = [
+// TRACKER SONGS
+// A track is a list of patterns, and an order to play them in. Each pattern row has a cell for every
+// channel: two pulses, the triangle and the noise, in that order.
+pub const PATTERN_ROWS: usize = 16;
+pub const N_CHANNELS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Cell {
+    // keep doing whatever the channel was doing
+    Hold,
+    // cut the note off
+    Off,
+    // a MIDI note number (60 is middle C). On the noise channel, higher is hissier.
+    Note(u8),
+}
+
+pub type Pattern = [[Cell; N_CHANNELS]; PATTERN_ROWS];
+
+// how a channel sounds when it gets a note.
+pub struct Instrument {
+    // TONE_MODE1-4 for the pulses' duty cycle, 0 otherwise
+    pub mode: u32,
+    pub volume: u8,
+    // envelope, in frames
+    pub attack: u8,
+    pub sustain: u8,
+    pub release: u8,
+}
+
+pub struct Track {
+    // frames per row. Lower is faster.
+    pub frames_per_row: u8,
+    pub instruments: [Instrument; N_CHANNELS],
+    pub patterns: &'static [Pattern],
+    // which pattern to play at each step of the song
+    pub order: &'static [u8],
+    // where to go back to in the order after the end, or stop
+    pub loop_to: Option<u8>,
+}
+
+// one call to tone(), kept as data so it can be played later (or checked in a test).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ToneCall {
+    pub frequency: u32,
+    pub duration: u32,
+    pub volume: u32,
+    pub flags: u32,
+}
+
+impl ToneCall {
+    pub fn play(self: &Self) {
+        tone(self.frequency, self.duration, self.volume, self.flags);
+    }
+}
+
+pub fn note_to_freq(note: u8) -> u32 {
+    // equal temperament, with A4 (note 69) at 440 Hz
+    (440.0 * num::Float::powf(2.0f32, (note as f32 - 69.0) / 12.0)) as u32
+}
+
+const ___: Cell = Cell::Hold;
+const OFF: Cell = Cell::Off;
+const fn n(note: u8) -> Cell {
+    Cell::Note(note)
+}
+
+// drums on the noise channel
+const KCK: Cell = n(30);
+const SNR: Cell = n(72);
+const HAT: Cell = n(96);
+
+const PURR_PATTERNS: [Pattern; 3] = [
+    // verse
+    [
+        [n(72), n(64), n(48), KCK],
+        [___, ___, ___, ___],
+        [n(76), ___, ___, HAT],
+        [___, ___, ___, ___],
+        [n(79), ___, n(43), SNR],
+        [___, ___, ___, ___],
+        [n(76), ___, ___, HAT],
+        [___, OFF, ___, ___],
+        [n(77), n(65), n(41), KCK],
+        [___, ___, ___, ___],
+        [n(81), ___, ___, HAT],
+        [___, ___, ___, KCK],
+        [n(79), ___, n(43), SNR],
+        [___, ___, ___, ___],
+        [OFF, OFF, ___, HAT],
+        [___, ___, ___, ___],
+    ],
+    // answer
+    [
+        [n(81), n(65), n(41), KCK],
+        [___, ___, ___, ___],
+        [n(79), ___, ___, HAT],
+        [___, ___, ___, ___],
+        [n(76), n(64), n(45), SNR],
+        [___, ___, ___, ___],
+        [n(74), ___, ___, HAT],
+        [___, ___, ___, ___],
+        [n(74), n(62), n(43), KCK],
+        [___, ___, ___, ___],
+        [n(76), ___, ___, HAT],
+        [___, ___, ___, KCK],
+        [n(72), n(64), n(48), SNR],
+        [___, ___, ___, HAT],
+        [___, ___, ___, SNR],
+        [OFF, OFF, ___, SNR],
+    ],
+    // turnaround
+    [
+        [n(72), n(67), n(48), KCK],
+        [___, ___, ___, ___],
+        [n(67), ___, ___, HAT],
+        [___, ___, ___, ___],
+        [n(72), ___, n(43), SNR],
+        [___, ___, ___, ___],
+        [n(76), ___, ___, HAT],
+        [___, ___, ___, ___],
+        [n(84), n(72), n(36), KCK],
+        [___, ___, ___, ___],
+        [___, ___, ___, ___],
+        [___, ___, ___, ___],
+        [OFF, OFF, n(43), SNR],
+        [___, ___, ___, SNR],
+        [___, ___, ___, SNR],
+        [___, ___, ___, SNR],
+    ],
+];
+
+pub const SONGS: [Song; 11] = [
     Song { // happy cat
         name: "happy",
-        scale: [294, 330, 370, 392, 440, 494, 554, 587],
-        f1_pitchchange_timer: 5,
-        f2_pitchchange_timer: 3,
-        measure_length: 12,
-        f1_note_duration: 5,
-        f2_note_duration: 3,
-        time_signature: (3, 4),
+        mode: SongMode::Generated(Generator {
+            scale: [294, 330, 370, 392, 440, 494, 554, 587],
+            f1_pitchchange_timer: 5,
+            f2_pitchchange_timer: 3,
+            measure_length: 12,
+            f1_note_duration: 5,
+            f2_note_duration: 3,
+            time_signature: (3, 4),
+        }),
     },
     Song { // it works but how
         name: "ptdyl",
-        scale: [330, 370, 415, 440, 494, 554, 622, 659],
-        f1_pitchchange_timer: 3,
-        f2_pitchchange_timer: 8,
-        measure_length: 19,
-        f1_note_duration: 6,
-        f2_note_duration: 44,
-        time_signature: (2, 4),
+        mode: SongMode::Generated(Generator {
+            scale: [330, 370, 415, 440, 494, 554, 622, 659],
+            f1_pitchchange_timer: 3,
+            f2_pitchchange_timer: 8,
+            measure_length: 19,
+            f1_note_duration: 6,
+            f2_note_duration: 44,
+            time_signature: (2, 4),
+        }),
     },
     Song { // sneak cat
         name: "sneak",
-        scale: [196, 220, 247, 262, 294, 330, 370, 392],
-        f1_pitchchange_timer: 17,
-        f2_pitchchange_timer: 5,
-        measure_length: 30,
-        f1_note_duration: 10,
-        f2_note_duration: 20,
-        time_signature: (1, 3),
+        mode: SongMode::Generated(Generator {
+            scale: [196, 220, 247, 262, 294, 330, 370, 392],
+            f1_pitchchange_timer: 17,
+            f2_pitchchange_timer: 5,
+            measure_length: 30,
+            f1_note_duration: 10,
+            f2_note_duration: 20,
+            time_signature: (1, 3),
+        }),
     },
     Song { // rando_cat
         name: "boop!",
-        scale: [247, 277, 311, 330, 370, 415, 466, 494],
-        // scale: [196, 220, 247, 262, 294, 330, 370, 392],
-        f1_pitchchange_timer: 19,
-        f2_pitchchange_timer: 7,
-        measure_length: 4,
-        f1_note_duration: 1,
-        f2_note_duration: 26,
-        time_signature: (4, 4),
+        mode: SongMode::Generated(Generator {
+            scale: [247, 277, 311, 330, 370, 415, 466, 494],
+            // scale: [196, 220, 247, 262, 294, 330, 370, 392],
+            f1_pitchchange_timer: 19,
+            f2_pitchchange_timer: 7,
+            measure_length: 4,
+            f1_note_duration: 1,
+            f2_note_duration: 26,
+            time_signature: (4, 4),
+        }),
     },
     Song { // explore cat
         name: "explr",
-        scale: [196, 220, 247, 262, 294, 330, 370, 392],
-        // scale: [196, 220, 247, 262, 294, 330, 370, 392],
-        f1_pitchchange_timer: 7,
-        f2_pitchchange_timer: 11,
-        measure_length: 4,
-        f1_note_duration: 12,
-        f2_note_duration: 18,
-        time_signature: (12, 3),
+        mode: SongMode::Generated(Generator {
+            scale: [196, 220, 247, 262, 294, 330, 370, 392],
+            // scale: [196, 220, 247, 262, 294, 330, 370, 392],
+            f1_pitchchange_timer: 7,
+            f2_pitchchange_timer: 11,
+            measure_length: 4,
+            f1_note_duration: 12,
+            f2_note_duration: 18,
+            time_signature: (12, 3),
+        }),
     },
     Song { // c6 minor
         name: "ionos",
-        scale: [523, 587, 622, 698, 784, 831, 932, 1047],
-        f1_pitchchange_timer: 10,
-        f2_pitchchange_timer: 13,
-        measure_length: 11,
-        f1_note_duration: 10,
-        f2_note_duration: 16,
-        time_signature: (4, 3),
+        mode: SongMode::Generated(Generator {
+            scale: [523, 587, 622, 698, 784, 831, 932, 1047],
+            f1_pitchchange_timer: 10,
+            f2_pitchchange_timer: 13,
+            measure_length: 11,
+            f1_note_duration: 10,
+            f2_note_duration: 16,
+            time_signature: (4, 3),
+        }),
     },
     Song { // a flat
         name: "crpkt",
-        scale: [415, 466, 523, 554, 622, 698, 784, 831],
-        f1_pitchchange_timer: 11,
-        f2_pitchchange_timer: 17,
-        measure_length: 24,
-        f1_note_duration: 16,
-        f2_note_duration: 24,
-        time_signature: (1, 3),
+        mode: SongMode::Generated(Generator {
+            scale: [415, 466, 523, 554, 622, 698, 784, 831],
+            f1_pitchchange_timer: 11,
+            f2_pitchchange_timer: 17,
+            measure_length: 24,
+            f1_note_duration: 16,
+            f2_note_duration: 24,
+            time_signature: (1, 3),
+        }),
     },
     Song { // g sharp
         name: "blopy",
-        scale: [415, 466, 523, 554, 622, 698, 784, 831],
-        f1_pitchchange_timer: 2,
-        f2_pitchchange_timer: 7,
-        measure_length: 3,
-        f1_note_duration: 5,
-        f2_note_duration: 7,
-        time_signature: (4, 6),
+        mode: SongMode::Generated(Generator {
+            scale: [415, 466, 523, 554, 622, 698, 784, 831],
+            f1_pitchchange_timer: 2,
+            f2_pitchchange_timer: 7,
+            measure_length: 3,
+            f1_note_duration: 5,
+            f2_note_duration: 7,
+            time_signature: (4, 6),
+        }),
     },
     Song { // f
         name: "Yumbo",
-        scale: [175, 196, 220, 233, 262, 293, 330, 349],
-        f1_pitchchange_timer: 5,
-        f2_pitchchange_timer: 7,
-        measure_length: 3,
-        f1_note_duration: 4,
-        f2_note_duration: 17,
-        time_signature: (4, 16),
+        mode: SongMode::Generated(Generator {
+            scale: [175, 196, 220, 233, 262, 293, 330, 349],
+            f1_pitchchange_timer: 5,
+            f2_pitchchange_timer: 7,
+            measure_length: 3,
+            f1_note_duration: 4,
+            f2_note_duration: 17,
+            time_signature: (4, 16),
+        }),
     },
     Song { // f
         name: "Underworld",
-        scale: [523, 587, 622, 698, 784, 831, 932, 1047],
-        f1_pitchchange_timer: 7,
-        f2_pitchchange_timer: 17,
-        measure_length: 3,
-        f1_note_duration: 5,
-        f2_note_duration: 13,
-        time_signature: (5, 7),
+        mode: SongMode::Generated(Generator {
+            scale: [523, 587, 622, 698, 784, 831, 932, 1047],
+            f1_pitchchange_timer: 7,
+            f2_pitchchange_timer: 17,
+            measure_length: 3,
+            f1_note_duration: 5,
+            f2_note_duration: 13,
+            time_signature: (5, 7),
+        }),
+    },
+    Song { // the first written-out one
+        name: "purrl",
+        mode: SongMode::Tracked(Track {
+            frames_per_row: 8,
+            instruments: [
+                Instrument { mode: TONE_MODE2, volume: 20, attack: 1, sustain: 12, release: 6 },
+                Instrument { mode: TONE_MODE3, volume: 10, attack: 2, sustain: 24, release: 8 },
+                Instrument { mode: 0, volume: 30, attack: 0, sustain: 30, release: 4 },
+                Instrument { mode: 0, volume: 12, attack: 0, sustain: 2, release: 3 },
+            ],
+            patterns: &PURR_PATTERNS,
+            order: &[0, 1, 0, 2],
+            loop_to: Some(0),
+        }),
     },
 ];
 
 
-pub fn play_bgm(timer: u32, song: &Song) {
-        
+// play this frame's notes, except on channels a sound effect has taken over.
+pub fn play_bgm(tones: [Option<ToneCall>; N_CHANNELS], ducked: [bool; N_CHANNELS]) {
+    for (channel, tone_call) in tones.iter().enumerate() {
+        match tone_call {
+            Some(tone_call) if !ducked[channel] => tone_call.play(),
+            _ => {}
+        }
+    }
+}
+
+// ADAPTIVE MUSIC
+// How the game wants the song played right now.
+pub struct MusicParams {
+    // 1.0 is the song's own speed
+    pub tempo: f32,
+    // 0 is none, up to 3 for drums on every eighth
+    pub percussion: u8,
+    pub sting: Option<Sting>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sting {
+    Victory,
+    Fail,
+}
+
+impl MusicParams {
+    pub const PLAIN: MusicParams = MusicParams { tempo: 1.0, percussion: 0, sting: None };
+}
+
+// Keeps our place in the song, so the tempo can change without jumping around in it.
+pub struct SongPlayer {
+    // how far into the song we are, in frames at the song's own speed
+    position: u32,
+    // part of a frame left over when the tempo isn't 1
+    leftover: f32,
+    last_song_timer: u32,
+    // the sting that's playing, and for how long
+    sting: Option<(Sting, u32)>,
+}
+
+impl SongPlayer {
+    pub fn new() -> SongPlayer {
+        SongPlayer { position: 0, leftover: 0.0, last_song_timer: 0, sting: None }
+    }
+
+    // move the song along by a frame, and get what to play.
+    pub fn advance(self: &mut Self, song_timer: u32, song: &Song, params: &MusicParams) -> [Option<ToneCall>; N_CHANNELS] {
+        // the song got started over
+        if song_timer <= self.last_song_timer {
+            self.position = 0;
+            self.leftover = 0.0;
+        }
+        self.last_song_timer = song_timer;
+
+        // STINGS
+        // these take over every channel, and the song waits for them to finish.
+        match (params.sting, self.sting) {
+            (Some(sting), Some((playing, _))) if sting == playing => {}
+            (Some(sting), _) => self.sting = Some((sting, 0)),
+            (None, _) => self.sting = None,
+        }
+        if let Some((sting, t)) = self.sting {
+            if t < STING_FRAMES {
+                self.sting = Some((sting, t + 1));
+                return sting_tones(sting, t, &song_scale(song));
+            }
+        }
+
+        // play every frame of the song we've caught up to. Whatever comes last on a channel wins.
+        let mut tones = [None; N_CHANNELS];
+        self.leftover += params.tempo;
+        while self.leftover >= 1.0 {
+            self.leftover -= 1.0;
+            for (channel, tone_call) in bgm_tones(self.position, song).iter().enumerate() {
+                if tone_call.is_some() {
+                    tones[channel] = *tone_call;
+                }
+            }
+            if tones[3].is_none() {
+                tones[3] = percussion_tone(self.position, params.percussion);
+            }
+            self.position += 1;
+        }
+        tones
+    }
+}
+
+pub const STING_FRAMES: u32 = 60;
+
+// the song's notes, low to high.
+fn song_scale(song: &Song) -> [u32; 8] {
+    match &song.mode {
+        SongMode::Generated(generator) => generator.scale.map(|f| f as u32),
+        SongMode::Tracked(track) => {
+            // a major scale up from the first note of the tune
+            let mut root = 60;
+            for row in track.patterns[track.order[0] as usize].iter() {
+                if let Cell::Note(note) = row[0] {
+                    root = note;
+                    break;
+                }
+            }
+            [0, 2, 4, 5, 7, 9, 11, 12].map(|step| note_to_freq(root + step))
+        }
+    }
+}
+
+fn sting_tones(sting: Sting, t: u32, scale: &[u32; 8]) -> [Option<ToneCall>; N_CHANNELS] {
+    let mut tones = [None; N_CHANNELS];
+    fn pulse(frequency: u32, duration: u32, flags: u32) -> Option<ToneCall> {
+        Some(ToneCall { frequency, duration, volume: 24, flags })
+    }
+    match sting {
+        // up the scale, and land an octave above the start
+        Sting::Victory => match t {
+            0 => {
+                tones[0] = pulse(scale[0], 6, TONE_PULSE1 | TONE_MODE2);
+                tones[2] = Some(ToneCall { frequency: scale[0] / 2, duration: 24, volume: 30, flags: TONE_TRIANGLE });
+                tones[3] = Some(ToneCall { frequency: 300, duration: 4, volume: 14, flags: TONE_NOISE });
+            }
+            6 => tones[0] = pulse(scale[2], 6, TONE_PULSE1 | TONE_MODE2),
+            12 => tones[0] = pulse(scale[4], 6, TONE_PULSE1 | TONE_MODE2),
+            18 => {
+                tones[0] = pulse(scale[7], 30, TONE_PULSE1 | TONE_MODE2);
+                tones[1] = pulse(scale[4], 30, TONE_PULSE2 | TONE_MODE3);
+                tones[2] = Some(ToneCall { frequency: scale[0], duration: 30, volume: 30, flags: TONE_TRIANGLE });
+            }
+            _ => {}
+        },
+        // down the scale, then slide off the bottom
+        Sting::Fail => match t {
+            0 => tones[0] = pulse(scale[4], 10, TONE_PULSE1 | TONE_MODE4),
+            10 => tones[0] = pulse(scale[3], 10, TONE_PULSE1 | TONE_MODE4),
+            20 => tones[0] = pulse(scale[2], 10, TONE_PULSE1 | TONE_MODE4),
+            30 => {
+                tones[0] = pulse(scale[0] | ((scale[0] / 2) << 16), 24, TONE_PULSE1 | TONE_MODE4);
+                tones[2] = Some(ToneCall { frequency: (scale[0] / 2) | ((scale[0] / 4) << 16), duration: 24, volume: 30, flags: TONE_TRIANGLE });
+            }
+            _ => {}
+        },
+    }
+    tones
+}
+
+// extra drums on the noise channel. Busier the higher the level.
+fn percussion_tone(position: u32, level: u8) -> Option<ToneCall> {
+    const BEAT_FRAMES: u32 = 32;
+    let kick = ToneCall { frequency: 90, duration: 3, volume: 18, flags: TONE_NOISE };
+    let hat = ToneCall { frequency: 1600, duration: 1, volume: 8, flags: TONE_NOISE };
+    match (level, position % BEAT_FRAMES) {
+        (0, _) => None,
+        (_, 0) => Some(kick),
+        (2..=3, p) if p == BEAT_FRAMES / 2 => Some(hat),
+        (3, p) if p % (BEAT_FRAMES / 4) == 0 => Some(hat),
+        _ => None,
+    }
+}
+
+// what each channel should start playing this frame, if anything.
+pub fn bgm_tones(timer: u32, song: &Song) -> [Option<ToneCall>; N_CHANNELS] {
+    match &song.mode {
+        SongMode::Generated(generator) => generated_tones(timer, generator),
+        SongMode::Tracked(track) => tracked_tones(timer, track),
+    }
+}
+
+fn generated_tones(timer: u32, song: &Generator) -> [Option<ToneCall>; N_CHANNELS] {
+    let mut tones = [None; N_CHANNELS];
 
     let freq1: usize = (timer as usize / song.f1_pitchchange_timer as usize) % song.scale.len();
     let freq2: usize = (timer as usize / song.f2_pitchchange_timer as usize) % song.scale.len();
@@ -127,9 +471,126 @@ pub fn play_bgm(timer: u32, song: &Song) {
     let time_signature_numerator: u32 = song.time_signature.0 as u32*song.measure_length as u32;
     let time_signature_denominator: u32 = song.time_signature.1 as u32*song.measure_length as u32;
     if timer % time_signature_numerator == 0 {
-        tone(song.scale[freq1] as u32, song.f1_note_duration as u32, 20, TONE_PULSE1);
+        tones[0] = Some(ToneCall { frequency: song.scale[freq1] as u32, duration: song.f1_note_duration as u32, volume: 20, flags: TONE_PULSE1 });
     }
     if timer % time_signature_denominator == 0 && (freq2 as i32).abs_diff(freq1 as i32) > 1 {
-        tone(song.scale[freq2] as u32, song.f2_note_duration as u32, 20, TONE_PULSE2);
+        tones[1] = Some(ToneCall { frequency: song.scale[freq2] as u32, duration: song.f2_note_duration as u32, volume: 20, flags: TONE_PULSE2 });
     }
-}
\ No newline at end of file
+    tones
+}
+
+fn tracked_tones(timer: u32, track: &Track) -> [Option<ToneCall>; N_CHANNELS] {
+    let mut tones = [None; N_CHANNELS];
+    let frames_per_row = track.frames_per_row.max(1) as u32;
+    if timer % frames_per_row != 0 {
+        return tones;
+    }
+
+    // find the row, wrapping around to the loop point
+    let song_rows = track.order.len() * PATTERN_ROWS;
+    let mut row = (timer / frames_per_row) as usize;
+    if row >= song_rows {
+        match track.loop_to {
+            Some(loop_to) => {
+                let loop_start = loop_to as usize * PATTERN_ROWS;
+                row = loop_start + (row - song_rows) % (song_rows - loop_start);
+            }
+            None => return tones,
+        }
+    }
+    let pattern = &track.patterns[track.order[row / PATTERN_ROWS] as usize];
+
+    const CHANNEL_FLAGS: [u32; N_CHANNELS] = [TONE_PULSE1, TONE_PULSE2, TONE_TRIANGLE, TONE_NOISE];
+    for (channel, cell) in pattern[row % PATTERN_ROWS].iter().enumerate() {
+        let instrument = &track.instruments[channel];
+        tones[channel] = match cell {
+            Cell::Hold => None,
+            // a silent note takes over the channel
+            Cell::Off => Some(ToneCall { frequency: 0, duration: 0, volume: 0, flags: CHANNEL_FLAGS[channel] }),
+            Cell::Note(note) => Some(ToneCall {
+                frequency: note_to_freq(*note),
+                duration: (instrument.attack as u32) << 24 | (instrument.release as u32) << 8 | instrument.sustain as u32,
+                volume: instrument.volume as u32,
+                flags: CHANNEL_FLAGS[channel] | instrument.mode,
+            }),
+        };
+    }
+    tones
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn purrl() -> &'static Track {
+        match &SONGS[SONGS.len() - 1].mode {
+            SongMode::Tracked(track) => track,
+            SongMode::Generated(_) => panic!("expected a tracked song"),
+        }
+    }
+
+    #[test]
+    fn tracked_songs_play_rows_and_loop() {
+        let track = purrl();
+        let frames_per_row = track.frames_per_row as u32;
+
+        // every channel starts on the first row, and nothing happens between rows
+        let first = tracked_tones(0, track);
+        assert!(first.iter().all(|t| t.is_some()));
+        assert_eq!(first[0].unwrap().frequency, 523);
+        assert_eq!(first[3].unwrap().flags & 3, TONE_NOISE);
+        assert!(tracked_tones(1, track).iter().all(|t| t.is_none()));
+
+        // after the last pattern it picks up from the loop point
+        let song_frames = (track.order.len() * PATTERN_ROWS) as u32 * frames_per_row;
+        for t in 0..song_frames {
+            assert_eq!(tracked_tones(song_frames + t, track), tracked_tones(t, track));
+        }
+    }
+
+    #[test]
+    fn tempo_and_stings_change_how_the_song_plays() {
+        let song = &SONGS[1];
+        let count_notes = |params: &MusicParams| {
+            let mut player = SongPlayer::new();
+            let mut n = 0;
+            for timer in 1..=600 {
+                n += player.advance(timer, song, params).iter().flatten().count();
+            }
+            n
+        };
+        // faster means more notes in the same time, and drums add more still
+        let plain = count_notes(&MusicParams::PLAIN);
+        let fast = count_notes(&MusicParams { tempo: 1.5, ..MusicParams::PLAIN });
+        let drums = count_notes(&MusicParams { percussion: 3, ..MusicParams::PLAIN });
+        assert!(fast > plain && drums > plain);
+
+        // a sting plays once from the start, and then the song picks back up where it was
+        let mut player = SongPlayer::new();
+        let victory = MusicParams { sting: Some(Sting::Victory), ..MusicParams::PLAIN };
+        let first = player.advance(1, song, &victory);
+        assert!(first[0].is_some() && first[2].is_some());
+        for timer in 2..=STING_FRAMES {
+            player.advance(timer, song, &victory);
+        }
+        let mut after = SongPlayer::new();
+        assert_eq!(player.advance(STING_FRAMES + 1, song, &victory), after.advance(1, song, &MusicParams::PLAIN));
+    }
+
+    #[test]
+    fn generated_songs_play_like_they_used_to() {
+        let song = match &SONGS[0].mode {
+            SongMode::Generated(generator) => generator,
+            SongMode::Tracked(_) => panic!("expected a generated song"),
+        };
+        // the happy song hits the first pulse every 36 frames and the second every 48
+        let tones = generated_tones(0, song);
+        assert_eq!(tones[0], Some(ToneCall { frequency: 294, duration: 5, volume: 20, flags: TONE_PULSE1 }));
+        assert_eq!(tones[1], None);
+        assert_eq!(generated_tones(36, song)[0].unwrap().frequency, 587);
+        // (unless the two would be too close together)
+        assert_eq!(generated_tones(48, song)[1], None);
+        assert_eq!(generated_tones(96, song)[1].unwrap().frequency, 294);
+        assert!(generated_tones(1, song).iter().all(|t| t.is_none()));
+    }
+}
@@ -0,0 +1,260 @@
+use core::f32::consts::{PI, TAU};
+
+// What a particle gets drawn as.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ParticleLook {
+    // the little cloud sprite, flipped to face the way it's moving
+    Cloud,
+    // a single pixel
+    Pixel,
+    // a plus that shrinks to a pixel halfway through its life
+    Sparkle,
+}
+
+// Where the particles start out, and which way they go.
+#[derive(Clone, Copy)]
+pub enum SpawnShape {
+    // one particle, going whichever way it's told
+    Point,
+    // evenly around a circle, heading outwards
+    Ring { n: u8, speed: f32 },
+    // evenly between two angles (inclusive), heading outwards
+    Fan { n: u8, speed: f32, from: f32, to: f32 },
+    // scattered around a box centered on the spot, sitting still
+    Box { n: u8, width: f32, height: f32 },
+    // on a circle, heading around it
+    Swirl { n: u8, radius: f32, speed: f32 },
+}
+
+#[derive(Clone, Copy)]
+pub struct EmitterDef {
+    pub look: ParticleLook,
+    // frames until the particle goes away
+    pub lifetime: u8,
+    // added to the y velocity every frame
+    pub gravity: f32,
+    // the velocity gets multiplied by this every frame
+    pub drag: f32,
+    // which palette colour to draw with (1 to 4). Clouds use the spritesheet's colours.
+    pub color_i: u16,
+    pub shape: SpawnShape,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Emitter {
+    // a single cloud, like for jumping or bubbles
+    Cloud,
+    // a ring of clouds, for found kitties and opened doors
+    CloudBurst,
+    // a cross of clouds, for pickups
+    PickupPuff,
+    // kicked up off the floor when landing
+    Dust,
+    // when a kitty gets found
+    Sparkles,
+    // around a cat as it warps, at both ends
+    WarpSwirl,
+    // when an ability card gets used
+    CardBurst,
+}
+
+impl Emitter {
+    pub fn def(self: Self) -> EmitterDef {
+        const CLOUD_LIFETIME: u8 = 18;
+        const CLOUD_DRAG: f32 = 0.75;
+
+        match self {
+            Emitter::Cloud => EmitterDef {
+                look: ParticleLook::Cloud,
+                lifetime: CLOUD_LIFETIME,
+                gravity: 0.0,
+                drag: CLOUD_DRAG,
+                color_i: 0,
+                shape: SpawnShape::Point,
+            },
+            Emitter::CloudBurst => EmitterDef {
+                shape: SpawnShape::Ring { n: 6, speed: 4.0 },
+                ..Emitter::Cloud.def()
+            },
+            Emitter::PickupPuff => EmitterDef {
+                shape: SpawnShape::Ring { n: 4, speed: 3.0 },
+                ..Emitter::Cloud.def()
+            },
+            Emitter::Dust => EmitterDef {
+                look: ParticleLook::Pixel,
+                lifetime: 12,
+                gravity: 0.08,
+                drag: 0.85,
+                color_i: 3,
+                // low and out to both sides
+                shape: SpawnShape::Fan { n: 6, speed: 1.5, from: PI * 1.05, to: PI * 1.95 },
+            },
+            Emitter::Sparkles => EmitterDef {
+                look: ParticleLook::Sparkle,
+                lifetime: 24,
+                gravity: -0.04,
+                drag: 0.9,
+                color_i: 4,
+                shape: SpawnShape::Box { n: 5, width: 14.0, height: 12.0 },
+            },
+            Emitter::WarpSwirl => EmitterDef {
+                look: ParticleLook::Pixel,
+                lifetime: 16,
+                gravity: 0.0,
+                drag: 0.92,
+                color_i: 4,
+                shape: SpawnShape::Swirl { n: 8, radius: 8.0, speed: 2.0 },
+            },
+            Emitter::CardBurst => EmitterDef {
+                look: ParticleLook::Sparkle,
+                lifetime: 14,
+                gravity: 0.0,
+                drag: 0.8,
+                color_i: 2,
+                shape: SpawnShape::Ring { n: 8, speed: 5.0 },
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub t: u8,
+    pub emitter: Emitter,
+}
+
+// Each particle is about 20 bytes, so the whole pool fits in ~1.3 KB of the 64 KB.
+pub const MAX_PARTICLES: usize = 64;
+
+// A fixed number of particles, with the live ones packed at the front.
+// When it's full, new particles just don't get made.
+pub struct ParticlePool {
+    particles: [Particle; MAX_PARTICLES],
+    n_alive: usize,
+    // for scattering particles around. Kept apart from the game's rng so effects don't change seeded runs.
+    scatter_seed: u32,
+}
+
+impl ParticlePool {
+    pub fn new() -> ParticlePool {
+        ParticlePool {
+            particles: [Particle { x: 0.0, y: 0.0, vx: 0.0, vy: 0.0, t: 0, emitter: Emitter::Cloud }; MAX_PARTICLES],
+            n_alive: 0,
+            scatter_seed: 1,
+        }
+    }
+
+    pub fn alive(self: &Self) -> &[Particle] {
+        &self.particles[..self.n_alive]
+    }
+
+    pub fn clear(self: &mut Self) {
+        self.n_alive = 0;
+    }
+
+    fn push(self: &mut Self, x: f32, y: f32, vx: f32, vy: f32, emitter: Emitter) {
+        if self.n_alive < MAX_PARTICLES {
+            self.particles[self.n_alive] = Particle { x, y, vx, vy, t: 0, emitter };
+            self.n_alive += 1;
+        }
+    }
+
+    // somewhere from -0.5 to 0.5
+    fn scatter(self: &mut Self) -> f32 {
+        self.scatter_seed = self.scatter_seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        (self.scatter_seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+    }
+
+    // one particle going a given way, whatever the emitter's shape.
+    pub fn emit_with_velocity(self: &mut Self, emitter: Emitter, x: f32, y: f32, vx: f32, vy: f32) {
+        self.push(x, y, vx, vy, emitter);
+    }
+
+    // a batch of particles from the emitter, around the spot.
+    pub fn emit(self: &mut Self, emitter: Emitter, x: f32, y: f32) {
+        match emitter.def().shape {
+            SpawnShape::Point => self.push(x, y, 0.0, 0.0, emitter),
+            SpawnShape::Ring { n, speed } => {
+                for i in 0..n {
+                    let angle = TAU * i as f32 / n as f32;
+                    self.push(x, y, speed * num::Float::cos(angle), speed * num::Float::sin(angle), emitter);
+                }
+            }
+            SpawnShape::Fan { n, speed, from, to } => {
+                for i in 0..n {
+                    let angle = from + (to - from) * i as f32 / (n - 1).max(1) as f32;
+                    self.push(x, y, speed * num::Float::cos(angle), speed * num::Float::sin(angle), emitter);
+                }
+            }
+            SpawnShape::Box { n, width, height } => {
+                for _ in 0..n {
+                    let px = x + width * self.scatter();
+                    let py = y + height * self.scatter();
+                    self.push(px, py, 0.0, 0.0, emitter);
+                }
+            }
+            SpawnShape::Swirl { n, radius, speed } => {
+                for i in 0..n {
+                    let angle = TAU * i as f32 / n as f32;
+                    let (c, s) = (num::Float::cos(angle), num::Float::sin(angle));
+                    self.push(x + radius * c, y + radius * s, -speed * s, speed * c, emitter);
+                }
+            }
+        }
+    }
+
+    pub fn update(self: &mut Self) {
+        let mut i = 0;
+        while i < self.n_alive {
+            let p = &mut self.particles[i];
+            let def = p.emitter.def();
+            p.x += p.vx;
+            p.y += p.vy;
+            p.vx *= def.drag;
+            p.vy *= def.drag;
+            p.vy += def.gravity;
+            p.t += 1;
+            if p.t > def.lifetime {
+                // swap the last live one into this slot, and look at it next
+                self.n_alive -= 1;
+                self.particles[i] = self.particles[self.n_alive];
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_fills_up_and_empties_out() {
+        let mut pool = ParticlePool::new();
+        for _ in 0..MAX_PARTICLES {
+            pool.emit(Emitter::CloudBurst, 10.0, 10.0);
+        }
+        assert_eq!(pool.alive().len(), MAX_PARTICLES);
+
+        // a ring heads out evenly, so it stays centered on the spot
+        pool.clear();
+        pool.emit(Emitter::CloudBurst, 10.0, 10.0);
+        pool.update();
+        let n = pool.alive().len() as f32;
+        let mid_x = pool.alive().iter().map(|p| p.x).sum::<f32>() / n;
+        let mid_y = pool.alive().iter().map(|p| p.y).sum::<f32>() / n;
+        assert!((mid_x - 10.0).abs() < 0.01 && (mid_y - 10.0).abs() < 0.01);
+
+        pool.emit(Emitter::Sparkles, 10.0, 10.0);
+        pool.emit(Emitter::Dust, 10.0, 10.0);
+        for _ in 0..=Emitter::Sparkles.def().lifetime {
+            pool.update();
+        }
+        assert_eq!(pool.alive().len(), 0);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::spritesheet::{PresetSprites, Sprite};
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum PickupTypes {
+    Clock,
+    Fish,
+    Key,
+    CardPack,
+}
+
+pub struct Pickup {
+    pub pickup_type: PickupTypes,
+    pub x_pos: f32,
+    pub y_pos: f32,
+    pub sprite: &'static Sprite,
+    pub collected: bool,
+}
+
+// how many of each pickup were collected during the current level.
+pub struct LevelTallies {
+    pub clocks: u8,
+    pub fish: u8,
+    pub keys: u8,
+    pub card_packs: u8,
+}
+
+pub const MAX_N_PICKUPS: usize = 8;
+
+// seconds (or score) gained from a clock.
+pub const CLOCK_PICKUP_TIME: u32 = 5;
+
+// each fish bumps the score multiplier by one, up to this.
+pub const MAX_SCORE_MULTIPLIER: u32 = 4;
+
+// number of random cards inside a card pack.
+pub const CARD_PACK_SIZE: usize = 2;
+
+impl Pickup {
+    pub fn new(pickup_type: PickupTypes, x_pos: f32, y_pos: f32) -> Pickup {
+        let preset_sprite_type = match pickup_type {
+            PickupTypes::Clock => PresetSprites::Clock,
+            PickupTypes::Fish => PresetSprites::FishPickup,
+            PickupTypes::Key => PresetSprites::KeyPickup,
+            PickupTypes::CardPack => PresetSprites::CardPackPickup,
+        };
+
+        Pickup {
+            pickup_type,
+            x_pos,
+            y_pos,
+            sprite: Sprite::from_preset(&preset_sprite_type),
+            collected: false,
+        }
+    }
+
+    // spawn rules: clocks are common, fish and card packs are rarer. Keys aren't part of the random pool.
+    pub fn random_type(roll: u64) -> PickupTypes {
+        match roll % 100 {
+            0..=49 => PickupTypes::Clock, // 50 % chance
+            50..=74 => PickupTypes::Fish, // 25 % chance
+            _ => PickupTypes::CardPack, // 25 % chance
+        }
+    }
+}
+
+impl LevelTallies {
+    pub fn new() -> LevelTallies {
+        LevelTallies {
+            clocks: 0,
+            fish: 0,
+            keys: 0,
+            card_packs: 0,
+        }
+    }
+
+    pub fn count(self: &mut Self, pickup_type: PickupTypes) {
+        let tally = match pickup_type {
+            PickupTypes::Clock => &mut self.clocks,
+            PickupTypes::Fish => &mut self.fish,
+            PickupTypes::Key => &mut self.keys,
+            PickupTypes::CardPack => &mut self.card_packs,
+        };
+        *tally = tally.saturating_add(1);
+    }
+}
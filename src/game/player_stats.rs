@@ -0,0 +1,66 @@
+use super::entities::OptionallyEnabledPlayer;
+
+// What each player has gotten up to this run.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PlayerStats {
+    pub kitties_found: u32,
+    pub cards_used: u32,
+    // in pixels, however they got around
+    pub distance_px: f32,
+}
+
+impl PlayerStats {
+    pub fn new() -> PlayerStats {
+        PlayerStats {
+            kitties_found: 0,
+            cards_used: 0,
+            distance_px: 0.0,
+        }
+    }
+
+    pub fn add_distance(self: &mut Self, dx: f32, dy: f32) {
+        self.distance_px += num::Float::sqrt(dx * dx + dy * dy);
+    }
+}
+
+// Everyone who's playing, best first: most kitties found, then most cards used, then furthest travelled.
+pub fn rank_players(players: &[OptionallyEnabledPlayer; 4]) -> Vec<(usize, PlayerStats)> {
+    let mut ranking: Vec<(usize, PlayerStats)> = Vec::new();
+    for (i, opt_p) in players.iter().enumerate() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            ranking.push((i, p.stats));
+        }
+    }
+    ranking.sort_by(|(_, a), (_, b)| {
+        b.kitties_found
+            .cmp(&a.kitties_found)
+            .then(b.cards_used.cmp(&a.cards_used))
+            .then(b.distance_px.total_cmp(&a.distance_px))
+    });
+    ranking
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::entities::Player;
+
+    #[test]
+    fn players_rank_by_kitties_then_cards_then_distance() {
+        let mut players = [
+            OptionallyEnabledPlayer::Enabled(Player::new()),
+            OptionallyEnabledPlayer::Disabled,
+            OptionallyEnabledPlayer::Enabled(Player::new()),
+            OptionallyEnabledPlayer::Enabled(Player::new()),
+        ];
+        for (i, (kitties_found, cards_used, dx)) in [(2, 0, 10.0), (0, 0, 0.0), (2, 1, 0.0), (2, 1, 30.0)].iter().enumerate() {
+            if let OptionallyEnabledPlayer::Enabled(p) = &mut players[i] {
+                p.stats.kitties_found = *kitties_found;
+                p.stats.cards_used = *cards_used;
+                p.stats.add_distance(*dx, 0.0);
+            }
+        }
+        let order: Vec<usize> = rank_players(&players).iter().map(|(i, _)| *i).collect();
+        assert_eq!(order, vec![3, 2, 0]);
+    }
+}
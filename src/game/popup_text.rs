@@ -3,7 +3,10 @@ pub enum PopupIcon {
     None,
     Clock,
     CatHead,
-    DownArrow
+    DownArrow,
+    Fish,
+    Key,
+    CardPack,
 }
 
 pub struct PopupText {
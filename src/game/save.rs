@@ -0,0 +1,157 @@
+// One finished endless run. A level of 0 is an empty spot on the leaderboard.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LeaderboardEntry {
+    pub level: u16,
+    pub score: u32,
+}
+
+pub const LEADERBOARD_SIZE: usize = 5;
+
+// What's kept on the disk between plays. The game only changes it and marks it dirty;
+// reading and writing the disk happens outside of the simulation.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SaveData {
+    // which daily challenge the player is on. The cart has no clock, so players set it themselves.
+    pub daily_day: u16,
+    // the last daily challenge that got tried, so each one only gets one go
+    pub last_daily_played: u16,
+    // the best endless runs, furthest first
+    pub endless_leaderboard: [LeaderboardEntry; LEADERBOARD_SIZE],
+}
+
+// so a blank disk (or somebody else's bytes) don't get read as a save
+const SAVE_MAGIC: [u8; 2] = *b"KG";
+// version 1 only had the daily challenge
+const SAVE_VERSION: u8 = 2;
+const ENTRY_SIZE: usize = 6;
+pub const SAVE_SIZE: usize = 7 + ENTRY_SIZE * LEADERBOARD_SIZE;
+
+impl SaveData {
+    pub fn new() -> SaveData {
+        SaveData {
+            daily_day: 1,
+            last_daily_played: 0,
+            endless_leaderboard: [LeaderboardEntry { level: 0, score: 0 }; LEADERBOARD_SIZE],
+        }
+    }
+
+    pub fn to_bytes(self: &Self) -> [u8; SAVE_SIZE] {
+        let mut bytes = [0; SAVE_SIZE];
+        bytes[0..2].copy_from_slice(&SAVE_MAGIC);
+        bytes[2] = SAVE_VERSION;
+        bytes[3..5].copy_from_slice(&self.daily_day.to_le_bytes());
+        bytes[5..7].copy_from_slice(&self.last_daily_played.to_le_bytes());
+        for (i, entry) in self.endless_leaderboard.iter().enumerate() {
+            let at = 7 + ENTRY_SIZE * i;
+            bytes[at..at + 2].copy_from_slice(&entry.level.to_le_bytes());
+            bytes[at + 2..at + 6].copy_from_slice(&entry.score.to_le_bytes());
+        }
+        bytes
+    }
+
+    // anything that isn't a save starts over fresh. Older saves keep what they had.
+    pub fn from_bytes(bytes: &[u8]) -> SaveData {
+        let mut save = SaveData::new();
+        if bytes.len() < 7 || bytes[0..2] != SAVE_MAGIC {
+            return save;
+        }
+        match bytes[2] {
+            1 | 2 => {
+                save.daily_day = u16::from_le_bytes([bytes[3], bytes[4]]);
+                save.last_daily_played = u16::from_le_bytes([bytes[5], bytes[6]]);
+            }
+            _ => return save,
+        }
+        if bytes[2] >= 2 && bytes.len() >= SAVE_SIZE {
+            for (i, entry) in save.endless_leaderboard.iter_mut().enumerate() {
+                let at = 7 + ENTRY_SIZE * i;
+                entry.level = u16::from_le_bytes([bytes[at], bytes[at + 1]]);
+                entry.score = u32::from_le_bytes([bytes[at + 2], bytes[at + 3], bytes[at + 4], bytes[at + 5]]);
+            }
+        }
+        save
+    }
+
+    pub fn can_play_daily(self: &Self) -> bool {
+        self.daily_day > self.last_daily_played
+    }
+
+    // pick which daily challenge to play. Any day that hasn't been tried yet is fine, so a new save can
+    // catch up to whatever day everyone else is on, but there's no going back to a day that's been tried.
+    // Returns whether the day changed.
+    pub fn set_daily(self: &mut Self, day: u16) -> bool {
+        let first_untried = self.last_daily_played.saturating_add(1);
+        let day = day.max(first_untried.min(self.daily_day));
+        let changed = day != self.daily_day;
+        self.daily_day = day;
+        changed
+    }
+
+    // put an endless run on the leaderboard, if it's good enough (by level reached, then score).
+    // Returns where it landed.
+    pub fn record_endless(self: &mut Self, level: u16, score: u32) -> Option<usize> {
+        let board = &mut self.endless_leaderboard;
+        let place = board.iter().position(|entry| (level, score) > (entry.level, entry.score))?;
+        for i in (place + 1..LEADERBOARD_SIZE).rev() {
+            board[i] = board[i - 1];
+        }
+        board[place] = LeaderboardEntry { level, score };
+        Some(place)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_read_back_and_blank_disks_start_fresh() {
+        let mut save = SaveData::new();
+        assert!(save.can_play_daily());
+        save.daily_day = 300;
+        save.last_daily_played = 300;
+        assert!(!save.can_play_daily());
+        save.record_endless(52, 1234);
+        assert_eq!(SaveData::from_bytes(&save.to_bytes()), save);
+
+        assert_eq!(SaveData::from_bytes(&[0; SAVE_SIZE]), SaveData::new());
+        assert_eq!(SaveData::from_bytes(&[]), SaveData::new());
+
+        // a version 1 save keeps its daily challenge
+        let old = SaveData::from_bytes(&[b'K', b'G', 1, 7, 0, 6, 0]);
+        assert_eq!((old.daily_day, old.last_daily_played), (7, 6));
+    }
+
+    #[test]
+    fn any_untried_daily_can_be_picked() {
+        let mut save = SaveData::new();
+        assert!(save.set_daily(300));
+        assert!(save.set_daily(12));
+        // there's no day 0
+        assert!(save.set_daily(0));
+        assert_eq!(save.daily_day, 1);
+
+        // once day 12 is tried, it and the days before it are locked
+        save.set_daily(12);
+        save.last_daily_played = 12;
+        assert!(!save.set_daily(2));
+        assert_eq!(save.daily_day, 12);
+        assert!(save.set_daily(40));
+        save.set_daily(5);
+        assert_eq!(save.daily_day, 13);
+    }
+
+    #[test]
+    fn endless_runs_rank_by_level_then_score() {
+        let mut save = SaveData::new();
+        assert_eq!(save.record_endless(50, 100), Some(0));
+        assert_eq!(save.record_endless(60, 10), Some(0));
+        assert_eq!(save.record_endless(50, 200), Some(1));
+        for _ in 0..LEADERBOARD_SIZE {
+            save.record_endless(70, 0);
+        }
+        // the board is full of better runs now
+        assert_eq!(save.record_endless(60, 10), None);
+        assert_eq!(save.endless_leaderboard[0], LeaderboardEntry { level: 70, score: 0 });
+    }
+}
@@ -0,0 +1,185 @@
+use crate::wasm4::*;
+
+use super::music::{ToneCall, N_CHANNELS};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sfx {
+    Jump,
+    Land,
+    KittyFound,
+    CardUse,
+    WarpCharge,
+    TimerLow,
+    LevelClear,
+}
+
+// one tone in an effect. If sweep_to isn't 0, the pitch slides over to it.
+#[derive(Clone, Copy)]
+pub struct SfxNote {
+    pub freq: u16,
+    pub sweep_to: u16,
+    pub frames: u8,
+    pub volume: u8,
+}
+
+pub struct SfxDef {
+    // TONE_PULSE1 etc., plus a duty cycle mode
+    pub flags: u32,
+    // effects only cut off ones with a lower (or the same) priority. Music is below all of them.
+    pub priority: u8,
+    pub notes: &'static [SfxNote],
+}
+
+impl Sfx {
+    pub fn def(self: Self) -> SfxDef {
+        const fn note(freq: u16, sweep_to: u16, frames: u8, volume: u8) -> SfxNote {
+            SfxNote { freq, sweep_to, frames, volume }
+        }
+
+        match self {
+            Sfx::Jump => SfxDef {
+                flags: TONE_PULSE2 | TONE_MODE3,
+                priority: 2,
+                notes: const { &[note(300, 620, 6, 14)] },
+            },
+            Sfx::Land => SfxDef {
+                flags: TONE_NOISE,
+                priority: 1,
+                notes: const { &[note(180, 90, 4, 12)] },
+            },
+            Sfx::KittyFound => SfxDef {
+                flags: TONE_PULSE1 | TONE_MODE2,
+                priority: 4,
+                notes: const { &[note(659, 0, 4, 22), note(880, 0, 4, 22), note(1319, 0, 8, 20)] },
+            },
+            Sfx::CardUse => SfxDef {
+                flags: TONE_PULSE2 | TONE_MODE1,
+                priority: 3,
+                notes: const { &[note(400, 1200, 8, 16), note(1200, 900, 4, 10)] },
+            },
+            Sfx::WarpCharge => SfxDef {
+                // as long as it takes the warp to charge up
+                flags: TONE_TRIANGLE,
+                priority: 2,
+                notes: const { &[note(200, 800, 25, 30)] },
+            },
+            Sfx::TimerLow => SfxDef {
+                flags: TONE_PULSE1 | TONE_MODE4,
+                priority: 3,
+                notes: const { &[note(1000, 0, 3, 16)] },
+            },
+            Sfx::LevelClear => SfxDef {
+                flags: TONE_PULSE1 | TONE_MODE2,
+                priority: 5,
+                notes: const { &[note(523, 0, 6, 24), note(659, 0, 6, 24), note(784, 0, 6, 24), note(1047, 0, 14, 24)] },
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct PlayingSfx {
+    sfx: Sfx,
+    note_i: u8,
+    t: u8,
+}
+
+// Steps effects along during the simulation, and keeps what should sound this frame for the renderer to play.
+// While an effect is on a channel, the music on that channel is ducked (left silent).
+pub struct SfxPlayer {
+    playing: [Option<PlayingSfx>; N_CHANNELS],
+    // what to start playing this frame
+    pub tones: [Option<ToneCall>; N_CHANNELS],
+    // which channels the music has to stay off of this frame
+    pub ducked: [bool; N_CHANNELS],
+}
+
+impl SfxPlayer {
+    pub fn new() -> SfxPlayer {
+        SfxPlayer {
+            playing: [None; N_CHANNELS],
+            tones: [None; N_CHANNELS],
+            ducked: [false; N_CHANNELS],
+        }
+    }
+
+    pub fn trigger(self: &mut Self, sfx: Sfx) {
+        let def = sfx.def();
+        let channel = (def.flags & 0b11) as usize;
+        match self.playing[channel] {
+            Some(playing) if playing.sfx.def().priority > def.priority => {}
+            _ => self.playing[channel] = Some(PlayingSfx { sfx, note_i: 0, t: 0 }),
+        }
+    }
+
+    pub fn tick(self: &mut Self) {
+        for channel in 0..N_CHANNELS {
+            self.tones[channel] = None;
+            self.ducked[channel] = false;
+            let playing = match &mut self.playing[channel] {
+                Some(playing) => playing,
+                None => continue,
+            };
+            let def = playing.sfx.def();
+            let note = def.notes[playing.note_i as usize];
+
+            if playing.t == 0 {
+                self.tones[channel] = Some(ToneCall {
+                    frequency: note.freq as u32 | (note.sweep_to as u32) << 16,
+                    duration: note.frames as u32,
+                    volume: note.volume as u32,
+                    flags: def.flags,
+                });
+            }
+            self.ducked[channel] = true;
+
+            playing.t += 1;
+            if playing.t >= note.frames {
+                playing.t = 0;
+                playing.note_i += 1;
+                if playing.note_i as usize >= def.notes.len() {
+                    self.playing[channel] = None;
+                }
+            }
+        }
+    }
+
+    pub fn play(self: &Self) {
+        for tone_call in self.tones.iter().flatten() {
+            tone_call.play();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effects_take_over_channels_by_priority() {
+        let mut sfx = SfxPlayer::new();
+        sfx.trigger(Sfx::KittyFound);
+        // lower priority on the same channel gets dropped
+        sfx.trigger(Sfx::TimerLow);
+        sfx.tick();
+        assert_eq!(sfx.tones[0].unwrap().frequency, 659);
+        assert!(sfx.ducked[0] && !sfx.ducked[1]);
+
+        // the notes play one after the other, then the music gets the channel back
+        let mut started = vec![];
+        for _ in 0..20 {
+            if let Some(tone_call) = sfx.tones[0] {
+                started.push(tone_call.frequency);
+            }
+            sfx.tick();
+        }
+        assert_eq!(started, vec![659, 880, 1319]);
+        assert!(!sfx.ducked[0]);
+
+        // higher priority cuts in, and sweeps go in the top half of the frequency
+        sfx.trigger(Sfx::Jump);
+        sfx.trigger(Sfx::CardUse);
+        sfx.tick();
+        assert_eq!(sfx.tones[1].unwrap().frequency, 400 | 1200 << 16);
+    }
+}
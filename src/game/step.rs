@@ -0,0 +1,901 @@
+use crate::wasm4::*;
+
+use super::{
+    ability_cards::AbilityCardUsageResult,
+    camera::{Camera, CameraStyles},
+    particles::Emitter,
+    player_stats::{rank_players, PlayerStats},
+    sfx::Sfx,
+    collision::{check_entity_collisions, get_bound_of_character, update_pos, AbsoluteBoundingBox},
+    effects::EffectTypes,
+    entities::{MovingEntity, OptionallyEnabledPlayer},
+    fixed::Fixed,
+    game_constants::{COUNTDOWN_TIMER_START, FINAL_LEVEL, SCREEN_HEIGHT_PX, SCREEN_WIDTH_PX, START_DIFFICULTY_LEVEL, TIMER_INTERACTIVE_START},
+    game_state::{GameState, KittyArrows, RunType},
+    menus::{GameMode, MenuTypes, Modal, NormalPlayModes, SelectMenuFocuses, SelectSetup},
+    popup_text::PopupIcon,
+    rng::{GameRng, Rng},
+    versus::VersusMatch,
+    daily::{daily_seed, DailyRun},
+    endless::EndlessRun,
+};
+
+impl GameState<'static> {
+    /// Advance the game by one frame, given every gamepad this frame and the buttons that were newly pressed.
+    /// Nothing gets drawn or played here, so this can be run as fast as needed.
+    pub fn step(self: &mut Self, gamepads: [u8; 4], btns_pressed_this_frame: [u8; 4]) {
+        self.song_timer += 1;
+
+        // POINT THE CAMERAS (cards come in from where things are in these views)
+        self.update_cameras();
+
+        // HIT-STOP: HOLD EVERYTHING STILL FOR A FEW FRAMES
+        let frozen = self.camera_effects.freeze_frames > 0;
+        self.camera_effects.tick();
+        // sounds keep going through it
+        self.sfx.tick();
+        if frozen {
+            return;
+        }
+
+        // CHECK IF WE NEED TO FREEZE CHARACTERS / GAMEPLAY ON SCREEN
+        let mut showing_modal = false;
+        match &self.game_mode {
+            GameMode::NormalPlay(play_mode) => {
+                match play_mode {
+                    NormalPlayModes::MainGameplay => {
+                        self.countdown_paused = false;
+                    }
+                    NormalPlayModes::HoverModal(_) => {
+                        showing_modal = true;
+                        self.countdown_paused = true;
+                    }
+                }
+            },
+            _ => {}
+        }
+
+        // CHECK IF CHARACTERS / CATS ARE COLLIDING
+        if !showing_modal {
+            check_entity_collisions(self);
+        }
+
+        // COUNT DOWN TIMED CARD EFFECTS
+        if !showing_modal {
+            for opt_p in self.players.iter_mut() {
+                if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                    p.effects.tick(&mut p.character, &self.map);
+                }
+            }
+        }
+
+        // MOVE THE PLAYERS
+        // (the cat running around on the title screen stays quiet, and doesn't count for anything)
+        let playing = match self.game_mode {
+            GameMode::NormalPlay(_) => true,
+            _ => false,
+        };
+        for (i, optional_player) in self.players.iter_mut().enumerate() {
+            let mut input = gamepads[i];
+
+            // ON TITLE SCREEN, MOVE PLAYER 1 BASED ON TIME
+            if i == 0 {
+                match self.game_mode {
+                    GameMode::StartScreen => {
+                        let mut move_n = (((self.song_timer / 10) * 31) % 29) as u8;
+                        move_n &= !(BUTTON_LEFT | BUTTON_RIGHT);
+                        input = move_n;
+                        match move_n {
+                            0..=2 => {
+                                input |= BUTTON_LEFT;
+                            },
+                            3..=6=> {
+                                input |= BUTTON_RIGHT;
+                            }
+                            _ => {}
+                        }
+                    },
+                    _ => {},
+                }
+            }
+
+            let start_pos = match optional_player {
+                OptionallyEnabledPlayer::Enabled(p) => Some((p.character.x_pos, p.character.y_pos)),
+                OptionallyEnabledPlayer::Disabled => None,
+            };
+            update_pos(
+                &self.map,
+                MovingEntity::OptionalPlayer(&mut *optional_player),
+                input,
+                self.godmode,
+                &mut self.particles,
+                match playing {
+                    true => Some(&mut self.sfx),
+                    false => None,
+                },
+            );
+
+            // keep track of how far everyone goes. Warps are too far to count.
+            if let (true, Some((x, y)), OptionallyEnabledPlayer::Enabled(p)) = (playing, start_pos, optional_player) {
+                const MAX_STEP_PX: f32 = 16.0;
+                let (dx, dy) = ((p.character.x_pos - x).to_f32(), (p.character.y_pos - y).to_f32());
+                if dx.abs() < MAX_STEP_PX && dy.abs() < MAX_STEP_PX {
+                    p.stats.add_distance(dx, dy);
+                }
+            }
+        }
+
+        // CREATE INPUTS FOR NPCS, THEN MOVE THEM
+        self.generate_npc_inputs();
+        for (i, npc) in self.npcs.iter_mut().enumerate() {
+            update_pos(
+                &self.map,
+                MovingEntity::NPC(npc),
+                self.npc_inputs[i],
+                self.godmode,
+                &mut self.particles,
+                None,
+            );
+        }
+
+        // MOVE THE SHADOW CATS, AFTER WHOEVER'S CLOSE BY
+        for enemy in self.enemies.iter_mut() {
+            const CHASE_RADIUS: Fixed = Fixed::from_int(70);
+            const JUMP_HEIGHT: Fixed = Fixed::from_int(8);
+            let mut nearest: Option<(Fixed, Fixed, Fixed)> = None;
+            for opt_p in self.players.iter() {
+                if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                    let (dx, dy) = (p.character.x_pos - enemy.x_pos, p.character.y_pos - enemy.y_pos);
+                    let dist = dx.abs() + dy.abs();
+                    match nearest {
+                        Some((_, _, d)) if d <= dist => {}
+                        _ if dx.abs() < CHASE_RADIUS && dy.abs() < CHASE_RADIUS => nearest = Some((dx, dy, dist)),
+                        _ => {}
+                    }
+                }
+            }
+            let input = match nearest {
+                None => 0,
+                Some((dx, dy, _)) => {
+                    let mut input = match dx > Fixed::ZERO {
+                        true => BUTTON_RIGHT,
+                        false => BUTTON_LEFT,
+                    };
+                    if dy < -JUMP_HEIGHT {
+                        input |= BUTTON_1;
+                    }
+                    input
+                }
+            };
+            update_pos(
+                &self.map,
+                MovingEntity::NPC(enemy),
+                input,
+                self.godmode,
+                &mut self.particles,
+                None,
+            );
+        }
+
+        // UPDATE PARTICLES
+        self.particles.update();
+
+        // Depending on what gamemode we're in, we do different update steps.
+        match &mut self.game_mode {
+            GameMode::NormalPlay(_) => {
+                self.popup_text_ringbuffer.update_popup_positions();
+
+                if !showing_modal {
+                    // holding up while pressing the card button toggles the minimap instead.
+                    let mut card_presses = btns_pressed_this_frame;
+                    for p_i in 0..self.players.len() {
+                        if gamepads[p_i] & BUTTON_UP != 0 && btns_pressed_this_frame[p_i] & BUTTON_2 != 0 {
+                            self.minimap_shown[p_i] = !self.minimap_shown[p_i];
+                            card_presses[p_i] &= !BUTTON_2;
+                        }
+                    }
+                    self.use_ability_cards(card_presses);
+
+                    // FILL IN THE MINIMAP WHERE PLAYERS HAVE BEEN
+                    for opt_p in self.players.iter() {
+                        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                            let bound = get_bound_of_character(&p.character);
+                            self.map.mark_visited(bound.x + bound.width as i32 / 2, bound.y + bound.height as i32 / 2);
+                        }
+                    }
+                }
+
+                // MOVE ABILITY CARD POSITIONS (along the top right of each player's own view)
+                for (p_i, opt_p) in self.players.iter_mut().enumerate() {
+                    if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                        let cards_x = self.cameras[p_i].view_width as i32 - 80;
+                        let n_cards = p.card_stack.cards.len();
+                        let n_combo_cards = match p.card_stack.current_combo() {
+                            Some(recipe) => recipe.cards.len(),
+                            None => 0,
+                        };
+                        for (i, card) in p.card_stack.cards.iter_mut().enumerate() {
+                            match card {
+                                Some(c) => {
+                                    c.target_x = Fixed::from_int(cards_x + 15 * i as i32);
+                                    c.target_y = Fixed::from_int(1);
+                                    // cards that would be used up in a combo dip down
+                                    if i >= n_cards - n_combo_cards {
+                                        c.target_y = Fixed::from_int(4);
+                                    }
+                                },
+                                None => {}
+                            }
+                        }
+                        p.card_stack.move_cards();
+                    }
+                }
+
+                if showing_modal {
+                    self.update_modal(btns_pressed_this_frame);
+                } else {
+                    self.update_main_gameplay();
+                }
+            }
+            GameMode::StartScreen => {
+                self.song_idx = 1;
+                if self.song_timer >= TIMER_INTERACTIVE_START && btns_pressed_this_frame[0] != 0 {
+                    self.game_mode = GameMode::SelectScreen(SelectSetup{current_selection: SelectMenuFocuses::RunType});
+                }
+                self.rng.next_for_input();
+            },
+            GameMode::SelectScreen(select_setup) => {
+                if btns_pressed_this_frame[0] & BUTTON_DOWN != 0 {
+                    select_setup.current_selection = match select_setup.current_selection {
+                        SelectMenuFocuses::RunType => SelectMenuFocuses::Assists,
+                        SelectMenuFocuses::Assists => SelectMenuFocuses::Motion,
+                        SelectMenuFocuses::Motion => SelectMenuFocuses::Camera,
+                        SelectMenuFocuses::Camera => SelectMenuFocuses::RunType,
+                    }
+                } else if btns_pressed_this_frame[0] & BUTTON_UP != 0 {
+                    select_setup.current_selection = match select_setup.current_selection {
+                        SelectMenuFocuses::RunType => SelectMenuFocuses::Camera,
+                        SelectMenuFocuses::Camera => SelectMenuFocuses::Motion,
+                        SelectMenuFocuses::Assists => SelectMenuFocuses::RunType,
+                        SelectMenuFocuses::Motion => SelectMenuFocuses::Assists,
+                    }
+                }
+
+                match select_setup.current_selection {
+                    SelectMenuFocuses::Assists => {
+                        if btns_pressed_this_frame[0] & (BUTTON_RIGHT | BUTTON_LEFT) != 0 {
+                            self.settings.kitty_arrows = match self.settings.kitty_arrows {
+                                KittyArrows::Auto => KittyArrows::On,
+                                KittyArrows::On => KittyArrows::Off,
+                                KittyArrows::Off => KittyArrows::Auto,
+                            }
+                        }
+                    },
+                    SelectMenuFocuses::Motion => {
+                        if btns_pressed_this_frame[0] & (BUTTON_RIGHT | BUTTON_LEFT) != 0 {
+                            self.settings.reduced_motion = !self.settings.reduced_motion;
+                        }
+                    },
+                    SelectMenuFocuses::Camera => {
+                        if btns_pressed_this_frame[0] & (BUTTON_RIGHT | BUTTON_LEFT) != 0 {
+                            self.settings.camera_style = match self.settings.camera_style {
+                                CameraStyles::Smart => CameraStyles::Room,
+                                CameraStyles::Room => CameraStyles::Classic,
+                                CameraStyles::Classic => CameraStyles::Smart,
+                            }
+                        }
+                    },
+                    SelectMenuFocuses::RunType => {
+                        // holding BUTTON_2 on the daily, left and right jump ten days at a time
+                        let picking_day = matches!(self.settings.run_type, RunType::Daily) && gamepads[0] & BUTTON_2 != 0;
+                        if picking_day {
+                            const DAY_JUMP: u16 = 10;
+                            let day = match (btns_pressed_this_frame[0] & BUTTON_LEFT != 0, btns_pressed_this_frame[0] & BUTTON_RIGHT != 0) {
+                                (true, false) => self.save.daily_day.saturating_sub(DAY_JUMP),
+                                (false, true) => self.save.daily_day.saturating_add(DAY_JUMP),
+                                _ => self.save.daily_day,
+                            };
+                            if self.save.set_daily(day) {
+                                self.save_dirty = true;
+                            }
+                        } else if btns_pressed_this_frame[0] & (BUTTON_RIGHT | BUTTON_LEFT) != 0 {
+                            self.settings.run_type = match self.settings.run_type {
+                                RunType::Casual => RunType::TimedMode,
+                                RunType::TimedMode => RunType::Speedrun(0),
+                                RunType::Speedrun(_) => RunType::Chaos,
+                                RunType::Chaos => RunType::Versus(3),
+                                RunType::Versus(_) => RunType::Daily,
+                                RunType::Daily => RunType::Endless,
+                                RunType::Endless => RunType::Casual,
+                            }
+                        }
+
+                        if btns_pressed_this_frame[0] & (BUTTON_2) != 0 {
+                            match self.settings.run_type {
+                                RunType::Speedrun(n) => self.settings.run_type = RunType::Speedrun(n + 1),
+                                // best of 3, 5 or 7
+                                RunType::Versus(n) => self.settings.run_type = RunType::Versus(if n >= 7 { 3 } else { n + 2 }),
+                                // on to the next day's challenge. There's no going back, so days can't be played twice.
+                                RunType::Daily => {
+                                    if self.save.set_daily(self.save.daily_day.saturating_add(1)) {
+                                        self.save_dirty = true;
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    },
+                }
+
+                // each daily challenge only gets one go
+                let daily_played = matches!(self.settings.run_type, RunType::Daily) && !self.save.can_play_daily();
+                if btns_pressed_this_frame[0] & BUTTON_1 != 0 && !daily_played {
+                    self.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
+                    for opt_p in self.players.iter_mut() {
+                        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                            p.stats = PlayerStats::new();
+                        }
+                    }
+                    self.versus = VersusMatch::new();
+                    self.endless = EndlessRun::new();
+                    match self.settings.run_type {
+                        RunType::Speedrun(n) => {
+                            self.rng = GameRng::FixedSeed(Rng::new_from_seed(n), Rng::new_from_seed(n));
+                        }
+                        RunType::Daily => {
+                            let seed = daily_seed(self.save.daily_day);
+                            self.rng = GameRng::FixedSeed(Rng::new_from_seed(seed), Rng::new_from_seed(seed));
+                            // it counts as tried as soon as it starts
+                            self.save.last_daily_played = self.save.daily_day;
+                            self.save_dirty = true;
+                            self.daily = DailyRun::new();
+                        }
+                        _ => {}
+                    }
+                    self.regenerate_map();
+                }
+            }
+        }
+    }
+
+    /// Which players get a view of their own: everyone, when several are playing on this machine (not in netplay).
+    /// Otherwise nobody, and the screen is just the one player's.
+    pub fn split_screen_players(self: &Self) -> Vec<usize> {
+        if self.netplay {
+            return vec![];
+        }
+        match self.game_mode {
+            GameMode::NormalPlay(_) => {}
+            _ => return vec![],
+        }
+        let mut enabled: Vec<usize> = vec![];
+        for (i, opt_p) in self.players.iter().enumerate() {
+            if let OptionallyEnabledPlayer::Enabled(_) = opt_p {
+                enabled.push(i);
+            }
+        }
+        match enabled.len() {
+            0 | 1 => vec![],
+            _ => enabled,
+        }
+    }
+
+    /// Point each player's camera at them, in their part of the screen. Cameras of players who aren't playing just settle.
+    pub fn update_cameras(self: &mut Self) {
+        let split_players = self.split_screen_players();
+        for (i, opt_p) in self.players.iter().enumerate() {
+            let camera = &mut self.cameras[i];
+            camera.settings = self.settings.camera_style.settings();
+            camera.set_viewport(match split_players.iter().position(|&p_i| p_i == i) {
+                Some(view_i) => Camera::split_viewport(split_players.len(), view_i),
+                None => Camera::split_viewport(1, 0),
+            });
+            match opt_p {
+                OptionallyEnabledPlayer::Disabled => {
+                    camera.slew();
+                }
+                OptionallyEnabledPlayer::Enabled(player) => {
+                    let bound = get_bound_of_character(&player.character);
+                    camera.follow(
+                        bound.x as f32 + bound.width as f32 / 2.0,
+                        bound.y as f32 + bound.height as f32 / 2.0,
+                        player.character.is_facing_right,
+                        &self.map,
+                    );
+                }
+            }
+        }
+    }
+
+    /// How many NPCs are following players right now.
+    pub fn count_found_npcs(self: &Self) -> u32 {
+        self.npcs
+            .iter()
+            .fold(0, |acc, e| acc + match e.following_i {None => 0, Some(_) => 1})
+    }
+
+    fn generate_npc_inputs(self: &mut Self) {
+        let inputs = &mut self.npc_inputs;
+        for i in 0..self.npcs.len() {
+            let rng = &mut self.rng;
+            let rand_val = (rng.next_for_input() % 255) as u8;
+            let current_npc = &mut self.npcs[i];
+            let mut use_rng_input = false;
+            match current_npc.following_i {
+                None => {
+                    use_rng_input = true;
+
+                    // unfound kitties close to a player with a magnet get pulled towards them
+                    for opt_p in self.players.iter() {
+                        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                            const MAGNET_RADIUS: Fixed = Fixed::from_int(50);
+                            let ch = &p.character;
+                            if p.effects.is_active(EffectTypes::Magnet)
+                                && (ch.x_pos - current_npc.x_pos).abs() < MAGNET_RADIUS
+                                && (ch.y_pos - current_npc.y_pos).abs() < MAGNET_RADIUS
+                            {
+                                use_rng_input = false;
+                                inputs[i] = 0;
+                                if current_npc.x_pos < ch.x_pos {
+                                    inputs[i] |= BUTTON_RIGHT;
+                                } else {
+                                    inputs[i] |= BUTTON_LEFT;
+                                }
+                                if current_npc.y_pos > ch.y_pos {
+                                    inputs[i] |= BUTTON_1;
+                                }
+                                break;
+                            }
+                        }
+                    }
+                }
+                Some(p_i) => {
+                    let the_opt_player = &self.players[p_i as usize];
+                    if let OptionallyEnabledPlayer::Enabled(p) = the_opt_player {
+                        let p_bound = get_bound_of_character(&p.character);
+                        let npc_bound: AbsoluteBoundingBox<i32, u32> =
+                            get_bound_of_character(&current_npc);
+                        let needs_teleport;
+                        {
+                            // teleportAyh-shon if needed
+                            const TELEPORT_AXIS_MIN_DIST: u32 = SCREEN_HEIGHT_PX as u32;
+                            if p_bound.x.abs_diff(npc_bound.x) > TELEPORT_AXIS_MIN_DIST
+                                || p_bound.y.abs_diff(npc_bound.y) > TELEPORT_AXIS_MIN_DIST
+                            {
+                                needs_teleport = true
+                            } else {
+                                needs_teleport = false
+                            }
+                        }
+
+                        if needs_teleport {
+                            current_npc.x_pos = Fixed::from_int(p_bound.x);
+                            current_npc.y_pos = Fixed::from_int(p_bound.y);
+                            current_npc.x_vel = Fixed::ZERO;
+                            current_npc.y_vel = Fixed::ZERO;
+                        } else {
+                            if rng.next_for_input() % 10 > 1 {
+                                inputs[i] = 0;
+
+                                // make NPCs tryhard when they're not in the same Y to get to exact x position to help with climbing
+                                let mut tryhard_get_to_0: bool = true;
+                                let ch = &p.character;
+                                // fall by doing nothing
+                                if current_npc.y_pos + Fixed::from_int(npc_bound.height as i32) < ch.y_pos {
+                                } else if current_npc.y_pos > ch.y_pos + Fixed::from_int(p_bound.height as i32) {
+                                    inputs[i] |= BUTTON_1;
+                                } else {
+                                    tryhard_get_to_0 = false;
+                                }
+
+                                if tryhard_get_to_0 {
+                                    if current_npc.x_pos < ch.x_pos {
+                                        inputs[i] |= BUTTON_RIGHT;
+                                    } else if current_npc.x_pos > ch.x_pos {
+                                        inputs[i] |= BUTTON_LEFT;
+                                    }
+                                } else {
+                                    if current_npc.x_pos + Fixed::from_int(npc_bound.width as i32) < ch.x_pos {
+                                        inputs[i] |= BUTTON_RIGHT;
+                                    } else if current_npc.x_pos > ch.x_pos + Fixed::from_int(p_bound.width as i32)
+                                    {
+                                        inputs[i] |= BUTTON_LEFT;
+                                    }
+                                }
+                            } else {
+                                use_rng_input = true;
+                            }
+                        }
+                    } else {
+                        use_rng_input = false;
+                    }
+                }
+            }
+
+            if use_rng_input {
+                if rand_val < 20 {
+                    inputs[i] = 0x10;
+                } else if rand_val < 40 {
+                    inputs[i] = 0x20;
+                } else if rand_val < 42 {
+                    inputs[i] = BUTTON_1;
+                } else {
+                    inputs[i] = 0x0;
+                }
+            }
+        }
+    }
+
+    fn use_ability_cards(self: &mut Self, btns_pressed_this_frame: [u8; 4]) {
+        for (p_i, pr) in self.players.iter_mut().enumerate() {
+            match pr {
+                OptionallyEnabledPlayer::Enabled(p) => {
+                    if btns_pressed_this_frame[p_i] & BUTTON_2 != 0 {
+                        let res = p.card_stack.try_use_cards();
+                        let added_t;
+                        let popup_t: Option<String>;
+                        let popup_icon: PopupIcon;
+                        match res {
+                            AbilityCardUsageResult::NothingHappened => {
+                                added_t = 0;
+                                popup_t = None;
+                                popup_icon = PopupIcon::None;
+                            },
+                            AbilityCardUsageResult::GainedTime(t) => {
+                                added_t = t;
+                                popup_t = Some(format![" +{}", t]);
+                                popup_icon = PopupIcon::Clock;
+                            },
+                            AbilityCardUsageResult::EnabledTimedEffect(effect, frames, t) => {
+                                let newly_started = p.effects.add(effect, frames, &mut p.character);
+                                added_t = t;
+                                if newly_started {
+                                    popup_t = Some(match effect {
+                                        EffectTypes::Fly => "fly!",
+                                        EffectTypes::Warp => "hold   : warp",
+                                        EffectTypes::Dash => "dash!",
+                                        EffectTypes::DoubleJump => "2x jump!",
+                                        EffectTypes::Magnet => "magnet!",
+                                        EffectTypes::Reveal => "reveal!",
+                                        EffectTypes::FreezeTimer => "freeze!",
+                                        EffectTypes::Shrink => "shrink!",
+                                    }.to_string());
+                                    popup_icon = match effect {
+                                        EffectTypes::Warp => PopupIcon::DownArrow,
+                                        _ => PopupIcon::None,
+                                    };
+                                } else if t > 0 {
+                                    popup_t = Some(format![" +{}", t]);
+                                    popup_icon = PopupIcon::Clock;
+                                } else {
+                                    // the effect just got extended
+                                    popup_t = Some("more!".to_string());
+                                    popup_icon = PopupIcon::None;
+                                }
+                            },
+                            AbilityCardUsageResult::WarpToNearestKitty => {
+                                let mut nearest: Option<(Fixed, Fixed, i64)> = None;
+                                for npc in self.npcs.iter() {
+                                    if npc.following_i.is_some() {
+                                        continue;
+                                    }
+                                    let (dx, dy) = ((npc.x_pos - p.character.x_pos).to_int() as i64, (npc.y_pos - p.character.y_pos).to_int() as i64);
+                                    let dist_sq = dx * dx + dy * dy;
+                                    match nearest {
+                                        Some((_, _, d)) if d <= dist_sq => {}
+                                        _ => nearest = Some((npc.x_pos, npc.y_pos, dist_sq)),
+                                    }
+                                }
+                                match nearest {
+                                    Some((x, y, _)) => {
+                                        self.particles.emit(Emitter::WarpSwirl, p.character.x_pos.to_f32() + 2.0, p.character.y_pos.to_f32() + 3.0);
+                                        self.particles.emit(Emitter::WarpSwirl, x.to_f32() + 2.0, y.to_f32() - 1.0);
+                                        p.character.x_pos = x;
+                                        p.character.y_pos = y - Fixed::from_int(4);
+                                        p.character.x_vel = Fixed::ZERO;
+                                        p.character.y_vel = Fixed::ZERO;
+                                        added_t = 0;
+                                        popup_t = Some("warp!".to_string());
+                                        popup_icon = PopupIcon::None;
+                                    }
+                                    None => {
+                                        // everyone's already found, so just give some time
+                                        added_t = 5;
+                                        popup_t = Some(" +5".to_string());
+                                        popup_icon = PopupIcon::Clock;
+                                    }
+                                }
+                            }
+                        }
+                        match popup_t {
+                            Some(pt) => {
+                                // burst out of the cat
+                                self.sfx.trigger(Sfx::CardUse);
+                                p.stats.cards_used += 1;
+                                self.particles.emit(Emitter::CardBurst, p.character.x_pos.to_f32() + 2.0, p.character.y_pos.to_f32() + 3.0);
+                                self.camera_effects.add_trauma(0.25);
+                                self.popup_text_ringbuffer.add_new_popup(p.character.x_pos.to_f32() - 14.0, p.character.y_pos.to_f32(), pt, popup_icon);
+                            }
+                            _ => {}
+                        }
+                        self.countdown_timer_msec += added_t * 60;
+                        self.countdown_timer_msec = self.countdown_timer_msec.min(100 * 60 - 1);
+                        self.score += added_t;
+                    }
+                },
+                OptionallyEnabledPlayer::Disabled => {},
+            }
+        }
+    }
+
+    // animate the modal that's up, and act on it once a button gets pressed.
+    fn update_modal(self: &mut Self, btns_pressed_this_frame: [u8; 4]) {
+        let m = match &mut self.game_mode {
+            GameMode::NormalPlay(NormalPlayModes::HoverModal(m)) => m,
+            _ => return,
+        };
+        m.update_position(self.song_timer);
+        m.timer += 1;
+
+        if !m.ready_to_show_text() {
+            return;
+        }
+        let btn_pressed = m.ready_to_select() && btns_pressed_this_frame[0] & (BUTTON_1 | BUTTON_2) != 0;
+
+        match m.menu_type {
+            MenuTypes::WonLevel => {
+                if btn_pressed {
+                    self.difficulty_level += 1;
+                    self.game_mode =
+                        GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                            AbsoluteBoundingBox {
+                                x: 45,
+                                y: 40,
+                                width: 70,
+                                height: 50,
+                            },
+                            MenuTypes::StartLevel,
+                        )));
+                    self.regenerate_map();
+                }
+            },
+            MenuTypes::StartLevel => {
+                let text_timer = match m.ready_to_select() {
+                    true => m.timer,
+                    false => 0,
+                };
+                if text_timer > 100 || btn_pressed {
+                    self.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
+                }
+            },
+            MenuTypes::Done | MenuTypes::WonGame | MenuTypes::MatchOver => {
+                if btn_pressed {
+                    self.difficulty_level = START_DIFFICULTY_LEVEL;
+                    self.game_mode = GameMode::StartScreen;
+                }
+            },
+            MenuTypes::StartGameMessage => {
+                if btn_pressed {
+                    self.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
+                }
+            }
+        }
+    }
+
+    // The results modals grow to fit a ranking of everyone under them, when more than one cat is playing.
+    fn results_modal_position(self: &Self, position: AbsoluteBoundingBox<i32, u32>) -> AbsoluteBoundingBox<i32, u32> {
+        const ROW_HEIGHT: u32 = 10;
+        const RANKING_WIDTH: u32 = 144;
+        match rank_players(&self.players).len() {
+            0 | 1 => position,
+            n => {
+                let extra_height = ROW_HEIGHT * n as u32 + 4;
+                let width = position.width.max(RANKING_WIDTH);
+                AbsoluteBoundingBox {
+                    x: (SCREEN_WIDTH_PX as u32 - width) as i32 / 2,
+                    y: position.y - extra_height as i32 / 2,
+                    width,
+                    height: position.height + extra_height,
+                }
+            }
+        }
+    }
+
+    // tutorial, win and lose checks, and the countdown.
+    fn update_main_gameplay(self: &mut Self) {
+        self.versus.tick();
+        self.endless.tick();
+
+        // HELP TEXT AT START OF GAME
+        if self.difficulty_level == 1 && self.countdown_timer_msec == COUNTDOWN_TIMER_START - 1 && self.tutorial_text_counter == 0 {
+            self.tutorial_text_counter += 1;
+            self.game_mode = GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                AbsoluteBoundingBox {
+                    x: 10,
+                    y: 10,
+                    width: 140,
+                    height: 140,
+                },
+                MenuTypes::StartGameMessage
+            )));
+        }
+
+        // ------- LEVEL WIN CONDITION -----------
+        if self.total_npcs_to_find == self.count_found_npcs() {
+            self.sfx.trigger(Sfx::LevelClear);
+            // flash the background and shake on the way into the modal
+            self.camera_effects.flash(3, 10);
+            self.camera_effects.add_trauma(0.6);
+            self.camera_effects.freeze(8);
+
+            let endless = matches!(self.settings.run_type, RunType::Endless);
+            if let RunType::Daily = self.settings.run_type {
+                self.daily.clear_level(self.speedrun_timer_msec);
+                if self.difficulty_level == FINAL_LEVEL {
+                    self.daily.finish(self.save.daily_day, self.speedrun_timer_msec, true);
+                }
+            }
+
+            // in versus, every level is a round of the match
+            let versus_match_over = match self.settings.run_type {
+                RunType::Versus(best_of) => {
+                    self.versus.finish_round(&self.npcs);
+                    Some(self.versus.is_over(best_of))
+                }
+                _ => None,
+            };
+            if let Some(true) = versus_match_over {
+                self.game_mode =
+                GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                    AbsoluteBoundingBox {
+                        x: 15,
+                        y: 30,
+                        width: 130,
+                        height: 34 + 10 * self.versus.standings(&self.players).len() as u32,
+                    },
+                    MenuTypes::MatchOver
+                )));
+                self.song_idx = 0;
+            } else if versus_match_over.is_none() && !endless && self.difficulty_level == FINAL_LEVEL {
+                self.game_mode =
+                GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                    self.results_modal_position(AbsoluteBoundingBox {
+                        x: 25,
+                        y: 35,
+                        width: 110,
+                        height: 65,
+                    }),
+                    MenuTypes::WonGame
+                )));
+                self.song_idx = 0;
+            } else {
+                self.game_mode =
+                GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                    self.results_modal_position(AbsoluteBoundingBox {
+                        x: 40,
+                        y: 40,
+                        width: 80,
+                        height: 50,
+                    }),
+                    MenuTypes::WonLevel
+                )));
+                self.song_idx = 0;
+            }
+
+            self.song_timer = 0;
+        }
+
+        // PROGRESS TIME, CHECK FOR GAME END
+        if !self.countdown_paused {
+            self.speedrun_timer_msec += 1;
+
+            // the freeze card stops the countdown, but not the speedrun clock.
+            let mut countdown_frozen = false;
+            for opt_p in self.players.iter() {
+                if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                    countdown_frozen |= p.effects.is_active(EffectTypes::FreezeTimer);
+                }
+            }
+            if !countdown_frozen {
+                self.countdown_timer_msec -= 1;
+            }
+
+            // tick through the last few seconds when the clock matters
+            const TIMER_LOW_SECONDS: i32 = 10;
+            if let RunType::TimedMode | RunType::Daily | RunType::Endless = self.settings.run_type {
+                let t = self.countdown_timer_msec as i32;
+                if t > 0 && t <= TIMER_LOW_SECONDS * 60 && t % 60 == 0 {
+                    self.sfx.trigger(Sfx::TimerLow);
+                }
+            }
+
+            // ---- LOSE CONDITION ----
+            match self.settings.run_type {
+                RunType::TimedMode | RunType::Daily | RunType::Endless => {
+                    if self.countdown_timer_msec <= 0 {
+                        self.song_idx = 0;
+                        // endless runs go up on the leaderboard, which gets shown under the result
+                        let mut height = 60;
+                        match self.settings.run_type {
+                            RunType::Daily => {
+                                self.daily.finish(self.save.daily_day, self.speedrun_timer_msec, false);
+                            }
+                            RunType::Endless => {
+                                self.endless.place = self.save.record_endless(self.difficulty_level as u16, self.score);
+                                self.save_dirty = true;
+                                height = 100;
+                            }
+                            _ => {}
+                        }
+
+                        self.game_mode = GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
+                            self.results_modal_position(AbsoluteBoundingBox {
+                                x: 15,
+                                y: 50 - (height as i32 - 60) / 2,
+                                width: 130,
+                                height,
+                            }),
+                            MenuTypes::Done
+                        )));
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spritesheet;
+
+    // press through the menus and play with scripted inputs, without drawing anything.
+    fn scripted_game(n_frames: u32) -> GameState<'static> {
+        spritesheet::Sprite::init_all_sprites_for_tests();
+
+        let mut game_state = GameState::new();
+        game_state.rng = GameRng::FixedSeed(Rng::new_from_seed(77), Rng::new_from_seed(77));
+        game_state.regenerate_map();
+
+        let mut previous = 0;
+        for frame in 0..n_frames {
+            let gamepad = match frame {
+                // title screen, then start a timed run from the select screen
+                120 => BUTTON_1,
+                130 => BUTTON_RIGHT,
+                150 => BUTTON_1,
+                0..=200 => 0,
+                _ => match (frame / 90) % 4 {
+                    0 => BUTTON_RIGHT,
+                    1 => BUTTON_RIGHT | BUTTON_1,
+                    2 => BUTTON_LEFT | BUTTON_2,
+                    _ => BUTTON_LEFT | BUTTON_1,
+                },
+            };
+            let pressed = gamepad & (gamepad ^ previous);
+            previous = gamepad;
+            game_state.step([gamepad, 0, 0, 0], [pressed, 0, 0, 0]);
+        }
+        game_state
+    }
+
+    #[test]
+    fn step_leaves_the_menus_and_plays() {
+        let game_state = scripted_game(60 * 60);
+        assert!(matches!(game_state.game_mode, GameMode::NormalPlay(_)));
+        assert!(matches!(game_state.settings.run_type, RunType::TimedMode));
+        assert!(game_state.countdown_timer_msec < COUNTDOWN_TIMER_START);
+    }
+
+    #[test]
+    fn step_is_deterministic() {
+        let (a, b) = (scripted_game(60 * 60), scripted_game(60 * 60));
+        let positions = |g: &GameState| -> Vec<(Fixed, Fixed)> {
+            g.npcs.iter().map(|c| (c.x_pos, c.y_pos)).collect()
+        };
+        assert_eq!(positions(&a), positions(&b));
+        assert_eq!(a.score, b.score);
+        assert_eq!(a.countdown_timer_msec, b.countdown_timer_msec);
+    }
+}
@@ -0,0 +1,318 @@
+// A software copy of the WASM-4 sound chip, so songs and sound effects can be rendered
+// off to WAV files and checked without running the cart. Only built for tests.
+//
+// It follows what tone() is documented to do: frequency slides, the ADSR envelope packed
+// in the duration, peak and sustain volumes, pulse duty cycles, pan and note mode.
+// The pulses aren't band-limited, so they're a bit harsher than in the real player.
+
+use crate::wasm4::*;
+
+use super::music::{MusicParams, Song, SongPlayer, ToneCall, N_CHANNELS};
+use super::sfx::{Sfx, SfxPlayer};
+
+pub const SAMPLE_RATE: u32 = 44100;
+const SAMPLES_PER_FRAME: u32 = SAMPLE_RATE / 60;
+// loud enough to hear, quiet enough for four channels to add up without clipping
+const MAX_VOLUME: f32 = 0x1333 as f32;
+const MAX_VOLUME_TRIANGLE: f32 = 0x2000 as f32;
+
+#[derive(Clone, Copy)]
+struct Channel {
+    freq1: f32,
+    freq2: f32,
+    // when each part of the envelope ends, in samples
+    start: u64,
+    attack_end: u64,
+    decay_end: u64,
+    sustain_end: u64,
+    release_end: u64,
+    peak_volume: f32,
+    sustain_volume: f32,
+    duty_cycle: f32,
+    pan: u32,
+    phase: f32,
+    noise_seed: u16,
+    noise_last: f32,
+}
+
+pub struct Synth {
+    channels: [Channel; N_CHANNELS],
+    // samples rendered so far
+    time: u64,
+}
+
+impl Synth {
+    pub fn new() -> Synth {
+        Synth {
+            channels: [Channel {
+                freq1: 0.0,
+                freq2: 0.0,
+                start: 0,
+                attack_end: 0,
+                decay_end: 0,
+                sustain_end: 0,
+                release_end: 0,
+                peak_volume: 0.0,
+                sustain_volume: 0.0,
+                duty_cycle: 0.5,
+                pan: 0,
+                phase: 0.0,
+                noise_seed: 1,
+                noise_last: 0.0,
+            }; N_CHANNELS],
+            time: 0,
+        }
+    }
+
+    // start a note, just like tone() would.
+    pub fn tone(self: &mut Self, tone_call: ToneCall) {
+        const NOTE_MODE: u32 = 64;
+
+        let channel = &mut self.channels[(tone_call.flags & 0b11) as usize];
+        let (mut freq1, mut freq2) = ((tone_call.frequency & 0xffff) as f32, (tone_call.frequency >> 16) as f32);
+        if tone_call.flags & NOTE_MODE != 0 {
+            // MIDI note numbers, with the high byte bending up in 256ths of a semitone
+            fn note_freq(note: f32) -> f32 {
+                440.0 * (2.0f32).powf((note - 69.0) / 12.0)
+            }
+            let (f1, f2) = (tone_call.frequency & 0xffff, tone_call.frequency >> 16);
+            freq1 = note_freq((f1 & 0xff) as f32 + (f1 >> 8) as f32 / 256.0);
+            if f2 != 0 {
+                freq2 = note_freq((f2 & 0xff) as f32 + (f2 >> 8) as f32 / 256.0);
+            }
+        }
+
+        let frames_to_samples = |frames: u32| frames as u64 * SAMPLES_PER_FRAME as u64;
+        let sustain = frames_to_samples(tone_call.duration & 0xff);
+        let release = frames_to_samples((tone_call.duration >> 8) & 0xff);
+        let decay = frames_to_samples((tone_call.duration >> 16) & 0xff);
+        let attack = frames_to_samples(tone_call.duration >> 24);
+
+        let max_volume = match tone_call.flags & 0b11 {
+            2 => MAX_VOLUME_TRIANGLE,
+            _ => MAX_VOLUME,
+        };
+        let sustain_volume = (tone_call.volume & 0xff).min(100);
+        let peak_volume = match (tone_call.volume >> 8) & 0xff {
+            0 => 100,
+            v => v.min(100),
+        };
+
+        channel.freq1 = freq1;
+        channel.freq2 = freq2;
+        channel.start = self.time;
+        channel.attack_end = self.time + attack;
+        channel.decay_end = channel.attack_end + decay;
+        channel.sustain_end = channel.decay_end + sustain;
+        channel.release_end = channel.sustain_end + release;
+        channel.sustain_volume = sustain_volume as f32 / 100.0 * max_volume;
+        channel.peak_volume = peak_volume as f32 / 100.0 * max_volume;
+        channel.duty_cycle = match (tone_call.flags >> 2) & 0b11 {
+            0 => 0.125,
+            1 => 0.25,
+            2 => 0.5,
+            _ => 0.75,
+        };
+        channel.pan = (tone_call.flags >> 4) & 0b11;
+    }
+
+    // render a frame's worth of sound, as interleaved left and right samples.
+    pub fn render_frame(self: &mut Self, out: &mut Vec<i16>) {
+        for _ in 0..SAMPLES_PER_FRAME {
+            let (mut left, mut right) = (0.0f32, 0.0f32);
+            for (channel_i, channel) in self.channels.iter_mut().enumerate() {
+                let t = self.time;
+                if t >= channel.release_end {
+                    continue;
+                }
+                fn ramp(from: f32, to: f32, t: u64, t1: u64, t2: u64) -> f32 {
+                    from + (to - from) * (t - t1) as f32 / (t2 - t1).max(1) as f32
+                }
+                let volume = if t < channel.attack_end {
+                    ramp(0.0, channel.peak_volume, t, channel.start, channel.attack_end)
+                } else if t < channel.decay_end {
+                    ramp(channel.peak_volume, channel.sustain_volume, t, channel.attack_end, channel.decay_end)
+                } else if t < channel.sustain_end {
+                    channel.sustain_volume
+                } else {
+                    ramp(channel.sustain_volume, 0.0, t, channel.sustain_end, channel.release_end)
+                };
+                // slides go over the whole note
+                let freq = match channel.freq2 > 0.0 {
+                    true => ramp(channel.freq1, channel.freq2, t, channel.start, channel.release_end),
+                    false => channel.freq1,
+                };
+
+                let sample = match channel_i {
+                    0 | 1 => {
+                        channel.phase = (channel.phase + freq / SAMPLE_RATE as f32) % 1.0;
+                        match channel.phase < channel.duty_cycle {
+                            true => volume,
+                            false => -volume,
+                        }
+                    }
+                    2 => {
+                        channel.phase = (channel.phase + freq / SAMPLE_RATE as f32) % 1.0;
+                        volume * (2.0 * (2.0 * channel.phase - 1.0).abs() - 1.0)
+                    }
+                    _ => {
+                        // the pitch sets how often a new random bit comes out
+                        channel.phase += freq * freq / (1_000_000.0 / SAMPLE_RATE as f32 * SAMPLE_RATE as f32);
+                        while channel.phase > 0.0 {
+                            channel.phase -= 1.0;
+                            channel.noise_seed ^= channel.noise_seed >> 7;
+                            channel.noise_seed ^= channel.noise_seed << 9;
+                            channel.noise_seed ^= channel.noise_seed >> 13;
+                            channel.noise_last = (2 * (channel.noise_seed & 1) as i32 - 1) as f32;
+                        }
+                        volume * channel.noise_last
+                    }
+                };
+
+                if channel.pan != TONE_PAN_RIGHT >> 4 {
+                    left += sample;
+                }
+                if channel.pan != TONE_PAN_LEFT >> 4 {
+                    right += sample;
+                }
+            }
+            out.push(left.clamp(i16::MIN as f32, i16::MAX as f32) as i16);
+            out.push(right.clamp(i16::MIN as f32, i16::MAX as f32) as i16);
+            self.time += 1;
+        }
+    }
+}
+
+// play a song (from the start, at its own speed) for a number of frames.
+pub fn render_song(song: &Song, n_frames: u32) -> Vec<i16> {
+    let mut synth = Synth::new();
+    let mut song_player = SongPlayer::new();
+    let mut samples = Vec::new();
+    for song_timer in 1..=n_frames {
+        for tone_call in song_player.advance(song_timer, song, &MusicParams::PLAIN).iter().flatten() {
+            synth.tone(*tone_call);
+        }
+        synth.render_frame(&mut samples);
+    }
+    samples
+}
+
+// play a sound effect until it's rung out.
+pub fn render_sfx(sfx: Sfx) -> Vec<i16> {
+    const TAIL_FRAMES: u32 = 10;
+
+    let mut synth = Synth::new();
+    let mut sfx_player = SfxPlayer::new();
+    let mut samples = Vec::new();
+    sfx_player.trigger(sfx);
+    let n_frames: u32 = sfx.def().notes.iter().map(|note| note.frames as u32).sum();
+    for _ in 0..n_frames + TAIL_FRAMES {
+        sfx_player.tick();
+        for tone_call in sfx_player.tones.iter().flatten() {
+            synth.tone(*tone_call);
+        }
+        synth.render_frame(&mut samples);
+    }
+    samples
+}
+
+// a 16 bit stereo WAV file.
+pub fn wav_bytes(samples: &[i16]) -> Vec<u8> {
+    const N_CHANNELS: u16 = 2;
+    const BYTES_PER_SAMPLE: u16 = 2;
+
+    let data_len = (samples.len() * BYTES_PER_SAMPLE as usize) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    // plain PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&N_CHANNELS.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * (N_CHANNELS * BYTES_PER_SAMPLE) as u32).to_le_bytes());
+    bytes.extend_from_slice(&(N_CHANNELS * BYTES_PER_SAMPLE).to_le_bytes());
+    bytes.extend_from_slice(&(BYTES_PER_SAMPLE * 8).to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::music::SONGS;
+
+    const ALL_SFX: [Sfx; 7] = [Sfx::Jump, Sfx::Land, Sfx::KittyFound, Sfx::CardUse, Sfx::WarpCharge, Sfx::TimerLow, Sfx::LevelClear];
+    const SONG_FRAMES: u32 = 10 * 60;
+
+    #[test]
+    fn tones_follow_their_envelope_and_pan() {
+        let mut synth = Synth::new();
+        // 5 frames of attack, 10 of sustain, 5 of release, on the left
+        synth.tone(ToneCall { frequency: 440, duration: 5 << 24 | 5 << 8 | 10, volume: 50, flags: TONE_PULSE1 | TONE_MODE3 | TONE_PAN_LEFT });
+        let mut samples = Vec::new();
+        for _ in 0..25 {
+            synth.render_frame(&mut samples);
+        }
+        let loudest_in_frame = |frame: usize| {
+            let per_frame = 2 * SAMPLES_PER_FRAME as usize;
+            samples[frame * per_frame..(frame + 1) * per_frame].iter().map(|s| s.unsigned_abs()).max().unwrap()
+        };
+        // ramps up to the peak, settles at the sustain volume, then fades out
+        assert!(loudest_in_frame(0) < loudest_in_frame(4));
+        assert!(loudest_in_frame(4) > loudest_in_frame(8));
+        assert_eq!(loudest_in_frame(8), (MAX_VOLUME / 2.0) as u16);
+        assert!(loudest_in_frame(17) < loudest_in_frame(8));
+        assert_eq!(loudest_in_frame(21), 0);
+        // nothing on the right
+        assert!(samples.iter().skip(1).step_by(2).all(|s| *s == 0));
+
+        // the duty cycle sets how much of each wave is high
+        let high = samples[8 * 2 * SAMPLES_PER_FRAME as usize..9 * 2 * SAMPLES_PER_FRAME as usize].iter().step_by(2).filter(|s| **s > 0).count();
+        assert!((high as f32 / SAMPLES_PER_FRAME as f32 - 0.5).abs() < 0.05);
+    }
+
+    // If music or sound effects change on purpose, update this to the new hash.
+    const GOLDEN_AUDIO_HASH: u64 = 0x8f7eca90d2377efd;
+
+    #[test]
+    fn songs_and_sfx_sound_the_same_as_before() {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut mix = |samples: &[i16]| {
+            for s in samples {
+                for b in s.to_le_bytes() {
+                    hash ^= b as u64;
+                    hash = hash.wrapping_mul(0x100000001b3);
+                }
+            }
+        };
+        for song in SONGS.iter() {
+            mix(&render_song(song, SONG_FRAMES));
+        }
+        for sfx in ALL_SFX {
+            mix(&render_sfx(sfx));
+        }
+        assert_eq!(hash, GOLDEN_AUDIO_HASH, "audio changed, got {:#x}", hash);
+    }
+
+    // `cargo test -- --ignored` writes everything out to target/audio to listen to.
+    #[test]
+    #[ignore]
+    fn render_everything_to_wav() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("audio");
+        std::fs::create_dir_all(&dir).unwrap();
+        for (i, song) in SONGS.iter().enumerate() {
+            let path = dir.join(format!["song-{:02}-{}.wav", i, song.name]);
+            std::fs::write(path, wav_bytes(&render_song(song, SONG_FRAMES))).unwrap();
+        }
+        for sfx in ALL_SFX {
+            let path = dir.join(format!["sfx-{:?}.wav", sfx].to_lowercase());
+            std::fs::write(path, wav_bytes(&render_sfx(sfx))).unwrap();
+        }
+    }
+}
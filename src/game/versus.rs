@@ -0,0 +1,133 @@
+use super::entities::{Character, OptionallyEnabledPlayer};
+
+// How a versus match is going: each level is a round, won by whoever has the most kitties following them at the end.
+pub struct VersusMatch {
+    pub round_wins: [u8; 4],
+    pub rounds_played: u8,
+    // None if the last round was a draw
+    pub last_round_winner: Option<usize>,
+    // frames until each player can be stolen from again, so kitties don't flip back and forth
+    pub steal_cooldowns: [u8; 4],
+}
+
+impl VersusMatch {
+    pub fn new() -> VersusMatch {
+        VersusMatch {
+            round_wins: [0; 4],
+            rounds_played: 0,
+            last_round_winner: None,
+            steal_cooldowns: [0; 4],
+        }
+    }
+
+    // how many kitties are following each player right now.
+    pub fn followers(npcs: &[Character]) -> [u32; 4] {
+        let mut counts = [0; 4];
+        for npc in npcs.iter() {
+            if let Some(i) = npc.following_i {
+                counts[i as usize] += 1;
+            }
+        }
+        counts
+    }
+
+    // whoever has the most followers, unless it's a tie.
+    pub fn round_winner(npcs: &[Character]) -> Option<usize> {
+        let counts = VersusMatch::followers(npcs);
+        let most = *counts.iter().max().unwrap();
+        match counts.iter().filter(|&&n| n == most).count() {
+            1 => counts.iter().position(|&n| n == most),
+            _ => None,
+        }
+    }
+
+    pub fn finish_round(self: &mut Self, npcs: &[Character]) {
+        let winner = VersusMatch::round_winner(npcs);
+        if let Some(i) = winner {
+            self.round_wins[i] += 1;
+        }
+        self.rounds_played += 1;
+        self.last_round_winner = winner;
+    }
+
+    // over once someone has won most of the rounds, or all of them have been played.
+    pub fn is_over(self: &Self, best_of: u8) -> bool {
+        self.round_wins.iter().any(|&wins| wins > best_of / 2) || self.rounds_played >= best_of
+    }
+
+    // everyone playing with how many rounds they won, most first.
+    pub fn standings(self: &Self, players: &[OptionallyEnabledPlayer; 4]) -> Vec<(usize, u8)> {
+        let mut standings: Vec<(usize, u8)> = Vec::new();
+        for (i, opt_p) in players.iter().enumerate() {
+            if let OptionallyEnabledPlayer::Enabled(_) = opt_p {
+                standings.push((i, self.round_wins[i]));
+            }
+        }
+        standings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        standings
+    }
+
+    // move one of the victim's followers over to the thief. Returns where it was, if there was one to take.
+    pub fn steal(self: &mut Self, npcs: &mut [Character], thief: usize, victim: usize) -> Option<(f32, f32)> {
+        const STEAL_COOLDOWN: u8 = 60;
+        if self.steal_cooldowns[victim] > 0 {
+            return None;
+        }
+        let npc = npcs.iter_mut().find(|npc| npc.following_i == Some(victim as u8))?;
+        npc.following_i = Some(thief as u8);
+        self.steal_cooldowns[victim] = STEAL_COOLDOWN;
+        // no stealing it straight back, either
+        self.steal_cooldowns[thief] = STEAL_COOLDOWN;
+        Some((npc.x_pos.to_f32(), npc.y_pos.to_f32()))
+    }
+
+    pub fn tick(self: &mut Self) {
+        for cooldown in self.steal_cooldowns.iter_mut() {
+            *cooldown = cooldown.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spritesheet::PresetSprites;
+
+    #[test]
+    fn rounds_and_steals_add_up_to_a_match() {
+        let mut npcs: Vec<Character> = (0..3).map(|_| Character::new(PresetSprites::Kitty1)).collect();
+        npcs[0].following_i = Some(0);
+        npcs[1].following_i = Some(2);
+        npcs[2].following_i = Some(2);
+        let mut versus = VersusMatch::new();
+        assert_eq!(VersusMatch::round_winner(&npcs), Some(2));
+
+        // pouncing on player 2 takes a kitty off them, but only once for a while
+        assert!(versus.steal(&mut npcs, 0, 2).is_some());
+        assert!(versus.steal(&mut npcs, 0, 2).is_none());
+        assert_eq!(VersusMatch::followers(&npcs), [2, 0, 1, 0]);
+
+        versus.finish_round(&npcs);
+        assert_eq!(versus.last_round_winner, Some(0));
+        assert!(!versus.is_over(3));
+
+        // a draw counts as a round, with nobody winning it
+        npcs[1].following_i = Some(2);
+        npcs[2].following_i = None;
+        versus.finish_round(&npcs);
+        assert_eq!(versus.last_round_winner, None);
+        assert!(!versus.is_over(3));
+
+        npcs[2].following_i = Some(0);
+        versus.finish_round(&npcs);
+        assert!(versus.is_over(3));
+
+        let players = [
+            OptionallyEnabledPlayer::Enabled(crate::game::entities::Player::new()),
+            OptionallyEnabledPlayer::Disabled,
+            OptionallyEnabledPlayer::Enabled(crate::game::entities::Player::new()),
+            OptionallyEnabledPlayer::Disabled,
+        ];
+        assert_eq!(versus.standings(&players), vec![(0, 2), (2, 0)]);
+    }
+}
@@ -3,6 +3,6 @@ pub const KITTY_SPRITESHEET: &[u8; 3072] = &KITTY_SS;
 
 
 // kitty_ss
-pub const KITTY_SS: [u8; 3072] = [ 0x05,0x05,0x41,0x40,0x08,0x08,0x00,0x00,0x2a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x14,0x51,0x14,0x50,0x22,0xa2,0x00,0x00,0xaa,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x50,0x54,0x54,0x14,0x08,0xc8,0x00,0x00,0x2a,0xa0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x51,0x14,0x04,0x20,0xc2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0x00,0x81,0x4c,0xff,0x31,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x05,0x41,0x50,0x20,0xc2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x4f,0xff,0xf1,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x05,0x41,0x50,0x23,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x4c,0xff,0x31,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x50,0x14,0x04,0x0c,0x08,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0x28,0x81,0x4f,0xff,0xf1,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x50,0x50,0x14,0x14,0x02,0xa0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x43,0xc3,0xc1,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x14,0x50,0x14,0x50,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xaa,0x01,0x43,0xff,0xc1,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x05,0x05,0x41,0x40,0x00,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x33,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0xff,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x50,0x05,0x54,0xc0,0x33,0xf0,0x00,0x00,0x00,0x00,0x00,0x03,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x3c,0x3f,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x10,0x00,0x00,0x30,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x33,0xf0,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xcf,0xff,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xc0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xf0,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xc0,0x00,0x00,0x40,0x3c,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x3c,0x00,0x00,0x40,0xff,0x01,0x40,0x3c,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x40,0xff,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0xff,0xc1,0x4f,0x3c,0xf1,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0x3c,0xc1,0x4c,0xff,0x31,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x15,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0xff,0xc1,0x40,0x3c,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x3c,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x40,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x54,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x01,0x54,0x55,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x05,0x50,0x15,0x40,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x54,0x55,0x50,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x55,0x55,0x54,0x0f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x45,0x45,0x54,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x50,0x15,0x50,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x05,0x50,0x15,0x40,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x01,0x50,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x45,0x44,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x50,0x41,0x10,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x45,0x54,0x44,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x45,0x41,0x10,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x55,0x44,0x44,0x3f,0xfc,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xfc,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xfc,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x44,0x11,0x10,0x30,0x0c,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x0c,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x80,0x00,0xa2,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x2a,0x00,0x00,0x00,0x02,0xa0,0x00,0x2a,0xa0,0x00,0x00,0x8a,0x00,0x00,0x00,0x80,0x88,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xa8,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0xa8,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0xa8,0xa8,0x00,0x00,0x00,0x02,0x20,0x00,0x0a,0x00,0x00,0x00,0x2a,0x00,0x00,0x00,0xa0,0x88,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0x20,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0x20,0x00,0x08,0xa8,0x00,0x00,0x00,0x0a,0x80,0x00,0x0a,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x2a,0xa8,0x00,0x00,0xaa,0x2a,0x00,0x00,0xa8,0x0a,0x80,0x00,0x00,0x02,0x20,0x00,0x0a,0xaa,0x80,0x00,0x00,0x02,0xa0,0x00,0x0a,0xaa,0x00,0x00,0x00,0x02,0xa0,0x00,0x0a,0x8a,0x00,0x00,0x80,0xa8,0xa0,0x00,0x0a,0xa0,0x00,0x00,0x0a,0x00,0x00,0x00,0x2a,0x88,0x00,0x00,0x2a,0xa2,0x00,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0xa0,0x00,0x0a,0xaa,0x00,0x00,0xaa,0xaa,0x20,0x00,0x0a,0xa8,0x00,0x00,0xaa,0xaa,0x20,0x00,0x2a,0x00,0x00,0x00,0x2a,0xa8,0x00,0x00,0x2a,0x00,0x00,0x00,0x0a,0x00,0x00,0x00,0x20,0xa0,0x00,0x00,0x20,0x28,0x00,0x00,0x0a,0xaa,0x00,0x00,0xaa,0xaa,0x20,0x00,0x20,0x08,0x00,0x00,0x82,0xaa,0x80,0x00,0x08,0x20,0x00,0x00,0x82,0xaa,0x80,0x00,0x20,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x20,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x0a,0xa8,0x00,0x00,0x82,0xaa,0x80,0x00,0x20,0x08,0x00,0x00,0x00,0x80,0x80,0x00,0x08,0x20,0x00,0x00,0x02,0x00,0x20,0x00,0x20,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x20,0x00,0x00,0x00,0xa0,0x00,0x00,0x00,0x20,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x20,0x00,0x00,0x00,0x00,0x00,0xa8,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0xa0,0x00,0x00,0x00,0x00,0x00,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x20,0x00,0x00,0x02,0x08,0x00,0xaa,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x80,0x00,0x00,0x02,0xa8,0x00,0x0a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x80,0xaa,0xa0,0x00,0x00,0x02,0x88,0x00,0x0a,0x00,0x00,0x00,0xaa,0x00,0x00,0x00,0x8a,0x2a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0x2a,0xa8,0x20,0x00,0x00,0x02,0xa8,0x00,0x02,0x80,0x00,0x00,0x22,0x00,0x00,0x00,0xaa,0xa2,0x00,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x02,0x00,0x00,0x00,0xaa,0xa0,0x00,0x00,0x28,0xa8,0x00,0x00,0x00,0x00,0x82,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x08,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x02,0x00,0x00,0x00,0x00,0x08,0x20,0x0a,0x00,0x00,0x00,0x00,0x2a,0x80,0x00,0x08,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x00,0x82,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x20,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x00,0x00,0x00,0x00,0xaa,0xa0,0x00,0xa8,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x28,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x20,0x00,0x00,0x00,0x00,0x00,0x00,0x2a,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x20,0x00,0x02,0x20,0x00,0xa0,0xaa,0x20,0x00,0x22,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x22,0x00,0x00,0x80,0xaa,0xa2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x00,0x00,0x00,0xa8,0x00,0x28,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x02,0xa0,0x00,0x2a,0xa8,0x00,0x00,0xaa,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x22,0x00,0x00,0xa2,0xaa,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x20,0x00,0x02,0x20,0x00,0x0a,0xaa,0xaa,0x80,0x00,0x02,0x20,0x00,0xaa,0xaa,0xaa,0x80,0x00,0x02,0x20,0x00,0x02,0xa0,0x00,0x00,0x0a,0x80,0x00,0x00,0x0a,0xa0,0x00,0x00,0xa0,0x2a,0x00,0x00,0x2a,0xaa,0xa8,0x00,0x00,0x02,0x20,0x00,0x00,0x00,0x0a,0xa0,0x2a,0x02,0xa0,0x00,0x0a,0xaa,0xaa,0x00,0x2a,0x02,0xa0,0x00,0x02,0xaa,0xaa,0x80,0x2a,0xaa,0x80,0x00,0x02,0x80,0x00,0x00,0x0a,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x0a,0xa2,0x00,0x00,0x02,0xa0,0xa8,0x00,0x2a,0x02,0xa0,0x00,0xaa,0xaa,0xaa,0x80,0xa2,0xaa,0x20,0x00,0x0a,0xaa,0xa8,0x00,0xa2,0xaa,0x20,0x00,0x02,0xaa,0xaa,0x00,0xa2,0xaa,0xa8,0x00,0x00,0x80,0x00,0x00,0x0a,0x80,0x00,0x00,0x08,0x00,0x00,0x00,0x28,0xa8,0x00,0x00,0x02,0x80,0x28,0x00,0xa2,0xaa,0x20,0x00,0x0a,0xaa,0xaa,0x80,0x02,0xaa,0x80,0x00,0x02,0x00,0x20,0x00,0x02,0xaa,0x80,0x00,0x02,0x00,0x02,0x00,0x02,0x00,0x00,0x00,0x00,0x80,0x00,0x00,0x08,0x00,0x00,0x00,0x08,0x00,0x00,0x00,0x20,0x20,0x00,0x00,0x02,0x00,0x08,0x00,0x02,0xaa,0x80,0x00,0x02,0xaa,0xaa,0x00,0x00,0x80,0x80,0x00,0x02,0x00,0x20,0x00,0x02,0x02,0x00,0x00,0x02,0x00,0x02,0x00,0x02,0x00,0x00,0x00,0x00,0x80,0x00,0x00,0x02,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x20,0x20,0x00,0x00,0x02,0x00,0x08,0x00 ];
+pub const KITTY_SS: [u8; 3072] = [ 0x05,0x05,0x41,0x40,0x08,0x08,0x00,0x00,0x2a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0xc0,0xa8,0x0f,0x00,0x00,0x55,0x50,0xa8,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x14,0x51,0x14,0x50,0x22,0xa2,0x00,0x00,0xaa,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0xf2,0xaa,0x30,0xff,0xc0,0x40,0x12,0x02,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x50,0x54,0x54,0x14,0x08,0xc8,0x00,0x00,0x2a,0xa0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0xfa,0x8a,0x30,0xcc,0xc5,0x55,0x13,0xff,0x40,0x00,0x01,0x40,0x3c,0x01,0x00,0x00,0x40,0x51,0x14,0x04,0x20,0xc2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0x00,0x81,0x4c,0xff,0x31,0x00,0x00,0xf2,0xaa,0x0f,0x00,0x04,0x01,0x13,0xcf,0x40,0x0c,0x01,0x40,0xc3,0x01,0x00,0x00,0x15,0x05,0x41,0x50,0x20,0xc2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x4f,0xff,0xf1,0x00,0x00,0xc0,0xa8,0x00,0x00,0x04,0xa1,0x13,0xff,0x4a,0x0f,0x01,0x43,0x00,0xc1,0x00,0x00,0x15,0x05,0x41,0x50,0x23,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x4c,0xff,0x31,0x00,0x00,0x00,0x00,0x00,0x00,0x04,0xa1,0x10,0x00,0x40,0x3f,0xf1,0x40,0x3c,0x01,0x00,0x00,0x40,0x50,0x14,0x04,0x0c,0x08,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0x28,0x81,0x4f,0xff,0xf1,0x00,0x00,0x00,0x00,0x00,0x00,0x04,0x01,0x00,0x00,0x4a,0x0f,0x01,0x40,0xc3,0x01,0x00,0x00,0x50,0x50,0x14,0x14,0x02,0xa0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x42,0xaa,0x81,0x43,0xc3,0xc1,0x00,0x00,0x00,0x00,0x00,0x00,0x05,0x55,0x00,0x00,0x40,0x0c,0x01,0x43,0x00,0xc1,0x00,0x00,0x14,0x50,0x14,0x50,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xaa,0x01,0x43,0xff,0xc1,0x00,0x00,0x3f,0xfc,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x05,0x05,0x41,0x40,0x00,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x33,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0xff,0x01,0x00,0x00,0xc8,0x23,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0xaa,0x01,0x00,0x00,0x55,0x50,0x05,0x54,0xc0,0x33,0xf0,0x00,0x00,0x00,0x00,0x00,0x03,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x3c,0x3f,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0xff,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x15,0x10,0x00,0x00,0x30,0x3f,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x33,0xf0,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xcf,0xff,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0xa0,0x00,0x82,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xc0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xf0,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x02,0x80,0x00,0xaa,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x15,0x10,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xc0,0x00,0x00,0x40,0x3c,0x01,0x40,0x00,0x01,0x00,0x00,0xaa,0x20,0x00,0xa2,0x00,0x00,0x00,0x00,0x43,0xff,0xc1,0x40,0x00,0x01,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x3c,0x00,0x00,0x40,0xff,0x01,0x40,0x3c,0x01,0x00,0x00,0x22,0xa0,0x02,0xa8,0x00,0x00,0x00,0x00,0x4f,0xff,0xf1,0x40,0xff,0x01,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x40,0xff,0x01,0x00,0x00,0xaa,0xa0,0x0a,0xa0,0x00,0x00,0x00,0x00,0x4f,0x00,0xf1,0x43,0x28,0xc1,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0xff,0xc1,0x4f,0x3c,0xf1,0x00,0x00,0x0a,0x80,0x2a,0x80,0x00,0x00,0x00,0x00,0x4f,0x00,0xf1,0x4c,0xaa,0x31,0x00,0x00,0x15,0x00,0x10,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0x3c,0xc1,0x4c,0xff,0x31,0x00,0x00,0x0a,0x80,0x2a,0x00,0x00,0x00,0x00,0x00,0x4f,0x00,0xf1,0x43,0x28,0xc1,0x00,0x00,0x15,0x00,0x15,0x54,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0xff,0xc1,0x40,0x3c,0x01,0x00,0x00,0x0a,0x80,0x2a,0x00,0x00,0x00,0x00,0x00,0x4a,0x00,0xa1,0x40,0xff,0x01,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x40,0x00,0x01,0x00,0x00,0x0a,0xa0,0x22,0x00,0x00,0x00,0x00,0x00,0x4a,0x00,0xa1,0x40,0x00,0x01,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x3c,0x01,0x40,0x00,0x01,0x00,0x00,0x0a,0x80,0xa2,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x15,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x28,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x55,0x40,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x03,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x00,0x55,0x54,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x01,0x54,0x55,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0x3c,0xc1,0x4c,0x00,0x31,0x00,0x00,0x05,0x50,0x15,0x40,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x43,0x00,0xc1,0x00,0x00,0x15,0x54,0x55,0x50,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xf0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x4c,0x3c,0x31,0x40,0x28,0x01,0x00,0x00,0x55,0x55,0x55,0x54,0x0f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xcf,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x4f,0xff,0xf1,0x40,0xaa,0x01,0x00,0x00,0x55,0x45,0x45,0x54,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x0f,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x4c,0x3c,0x31,0x40,0xaa,0x01,0x00,0x00,0x15,0x50,0x15,0x50,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0xf0,0xc0,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0xff,0x01,0x40,0x28,0x01,0x00,0x00,0x05,0x50,0x15,0x40,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x43,0x3c,0xc1,0x43,0x00,0xc1,0x00,0x00,0x01,0x50,0x15,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x4c,0x00,0x31,0x00,0x00,0x00,0x45,0x44,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x40,0x00,0x01,0x40,0x00,0x01,0x00,0x00,0x55,0x50,0x41,0x10,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x33,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x15,0x55,0x54,0x15,0x55,0x54,0x00,0x00,0x55,0x45,0x54,0x44,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xff,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x45,0x41,0x10,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0xff,0xf3,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x55,0x44,0x44,0x3f,0xfc,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xfc,0x00,0x00,0x00,0x00,0x00,0x00,0x3f,0xfc,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x55,0x44,0x11,0x10,0x30,0x0c,0x00,0x00,0x00,0x00,0x00,0x00,0x0c,0x30,0x00,0x00,0x00,0x00,0x00,0x00,0x30,0x0c,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x80,0x00,0xa2,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x2a,0x00,0x00,0x00,0x02,0xa0,0x00,0x2a,0xa0,0x00,0x00,0x8a,0x00,0x00,0x00,0x80,0x88,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0xa8,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0xa8,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0xa8,0xa8,0x00,0x00,0x00,0x02,0x20,0x00,0x0a,0x00,0x00,0x00,0x2a,0x00,0x00,0x00,0xa0,0x88,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0x20,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0x20,0x00,0x08,0xa8,0x00,0x00,0x00,0x0a,0x80,0x00,0x0a,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x2a,0xa8,0x00,0x00,0xaa,0x2a,0x00,0x00,0xa8,0x0a,0x80,0x00,0x00,0x02,0x20,0x00,0x0a,0xaa,0x80,0x00,0x00,0x02,0xa0,0x00,0x0a,0xaa,0x00,0x00,0x00,0x02,0xa0,0x00,0x0a,0x8a,0x00,0x00,0x80,0xa8,0xa0,0x00,0x0a,0xa0,0x00,0x00,0x0a,0x00,0x00,0x00,0x2a,0x88,0x00,0x00,0x2a,0xa2,0x00,0x00,0x08,0x08,0x80,0x00,0x00,0x02,0xa0,0x00,0x0a,0xaa,0x00,0x00,0xaa,0xaa,0x20,0x00,0x0a,0xa8,0x00,0x00,0xaa,0xaa,0x20,0x00,0x2a,0x00,0x00,0x00,0x2a,0xa8,0x00,0x00,0x2a,0x00,0x00,0x00,0x0a,0x00,0x00,0x00,0x20,0xa0,0x00,0x00,0x20,0x28,0x00,0x00,0x0a,0xaa,0x00,0x00,0xaa,0xaa,0x20,0x00,0x20,0x08,0x00,0x00,0x82,0xaa,0x80,0x00,0x08,0x20,0x00,0x00,0x82,0xaa,0x80,0x00,0x20,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x20,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x0a,0xa8,0x00,0x00,0x82,0xaa,0x80,0x00,0x20,0x08,0x00,0x00,0x00,0x80,0x80,0x00,0x08,0x20,0x00,0x00,0x02,0x00,0x20,0x00,0x20,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x20,0x00,0x00,0x00,0xa0,0x00,0x00,0x00,0x20,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x20,0x00,0x00,0x00,0x00,0x00,0xa8,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0xa0,0x00,0x00,0x00,0x00,0x00,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x20,0x00,0x00,0x02,0x08,0x00,0xaa,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x80,0x00,0x00,0x02,0xa8,0x00,0x0a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x80,0x22,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0x80,0xaa,0xa0,0x00,0x00,0x02,0x88,0x00,0x0a,0x00,0x00,0x00,0xaa,0x00,0x00,0x00,0x8a,0x2a,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0x2a,0xa8,0x20,0x00,0x00,0x02,0xa8,0x00,0x02,0x80,0x00,0x00,0x22,0x00,0x00,0x00,0xaa,0xa2,0x00,0x00,0x00,0x00,0x00,0x00,0xaa,0xa8,0x80,0x00,0x00,0x00,0x00,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x02,0x00,0x00,0x00,0xaa,0xa0,0x00,0x00,0x28,0xa8,0x00,0x00,0x00,0x00,0x82,0x00,0x0a,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x02,0x08,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x02,0x00,0x00,0x00,0x00,0x08,0x20,0x0a,0x00,0x00,0x00,0x00,0x2a,0x80,0x00,0x08,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x00,0x82,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x20,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x00,0x00,0x00,0x00,0xaa,0xa0,0x00,0xa8,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x20,0x20,0x00,0x00,0x00,0x28,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x08,0x20,0x00,0x00,0x00,0x00,0x00,0x00,0x2a,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x20,0x00,0x02,0x20,0x00,0xa0,0xaa,0x20,0x00,0x22,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x22,0x00,0x00,0x80,0xaa,0xa2,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x00,0x00,0x00,0xa8,0x00,0x28,0x80,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0xa0,0x00,0x02,0xa0,0x00,0x2a,0xa8,0x00,0x00,0xaa,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x00,0x22,0x00,0x00,0xa2,0xaa,0xaa,0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x0a,0x20,0x00,0x02,0x20,0x00,0x0a,0xaa,0xaa,0x80,0x00,0x02,0x20,0x00,0xaa,0xaa,0xaa,0x80,0x00,0x02,0x20,0x00,0x02,0xa0,0x00,0x00,0x0a,0x80,0x00,0x00,0x0a,0xa0,0x00,0x00,0xa0,0x2a,0x00,0x00,0x2a,0xaa,0xa8,0x00,0x00,0x02,0x20,0x00,0x00,0x00,0x0a,0xa0,0x2a,0x02,0xa0,0x00,0x0a,0xaa,0xaa,0x00,0x2a,0x02,0xa0,0x00,0x02,0xaa,0xaa,0x80,0x2a,0xaa,0x80,0x00,0x02,0x80,0x00,0x00,0x0a,0x00,0x00,0x00,0x0a,0x80,0x00,0x00,0x0a,0xa2,0x00,0x00,0x02,0xa0,0xa8,0x00,0x2a,0x02,0xa0,0x00,0xaa,0xaa,0xaa,0x80,0xa2,0xaa,0x20,0x00,0x0a,0xaa,0xa8,0x00,0xa2,0xaa,0x20,0x00,0x02,0xaa,0xaa,0x00,0xa2,0xaa,0xa8,0x00,0x00,0x80,0x00,0x00,0x0a,0x80,0x00,0x00,0x08,0x00,0x00,0x00,0x28,0xa8,0x00,0x00,0x02,0x80,0x28,0x00,0xa2,0xaa,0x20,0x00,0x0a,0xaa,0xaa,0x80,0x02,0xaa,0x80,0x00,0x02,0x00,0x20,0x00,0x02,0xaa,0x80,0x00,0x02,0x00,0x02,0x00,0x02,0x00,0x00,0x00,0x00,0x80,0x00,0x00,0x08,0x00,0x00,0x00,0x08,0x00,0x00,0x00,0x20,0x20,0x00,0x00,0x02,0x00,0x08,0x00,0x02,0xaa,0x80,0x00,0x02,0xaa,0xaa,0x00,0x00,0x80,0x80,0x00,0x02,0x00,0x20,0x00,0x02,0x02,0x00,0x00,0x02,0x00,0x02,0x00,0x02,0x00,0x00,0x00,0x00,0x80,0x00,0x00,0x02,0x00,0x00,0x00,0x28,0x00,0x00,0x00,0x20,0x20,0x00,0x00,0x02,0x00,0x08,0x00 ];
 
 
@@ -3,7 +3,7 @@
 //! [`kittygame`]: https://canyonturtle.github.io/kittygame/
 
 /// This is essentially the entrypoint of the game, providing the update() loop. 
-/// This has all the drawing code and lots of update logic.
+/// The game logic is stepped by GameState::step(), and everything gets drawn by render().
 
 
 mod alloc;
@@ -13,8 +13,8 @@ mod spritesheet;
 mod wasm4;
 
 use game::{
-    camera::Camera, cloud::Cloud, collision::{check_entity_collisions, update_pos}, entities::{Character, KittyStates, MovingEntity, WarpAbility, WarpState}, game_constants::{
-        MAX_N_NPCS, SCREEN_HEIGHT_PX, SCREEN_WIDTH_PX, TILE_HEIGHT_PX, TILE_WIDTH_PX
+    camera::{Camera, CameraStyles}, effects::EffectTypes, particles::ParticleLook, entities::{Character, KittyStates}, game_constants::{
+        SCREEN_HEIGHT_PX, SCREEN_WIDTH_PX, TILE_HEIGHT_PX, TILE_WIDTH_PX
     }, game_map::MAP_TILESETS, game_state::GameState, menus::GameMode, music::{play_bgm, SONGS}
 };
 
@@ -25,14 +25,41 @@ mod title_ss;
 
 use crate::{
     alloc::init_heap, game::{
-        collision::{get_bound_of_character, AbsoluteBoundingBox}, entities::OptionallyEnabledPlayer, game_constants::{COUNTDOWN_TIMER_START, FINAL_LEVEL, INCR_VERSION, LEVELS_PER_MOOD, MAJOR_VERSION, MINOR_VERSION, START_DIFFICULTY_LEVEL}, game_state::RunType, menus::{MenuTypes, Modal, NormalPlayModes, SelectMenuFocuses, SelectSetup}, popup_text::{PopTextRingbuffer, PopupIcon}, rng::{GameRng, Rng}
+        collision::{get_bound_of_door, AbsoluteBoundingBox}, entities::OptionallyEnabledPlayer, minimap::MinimapLayout, player_stats::rank_players, versus::VersusMatch, save::{SaveData, SAVE_SIZE}, endless::{darkened_palette, endless_tier}, game_constants::{INCR_VERSION, LEVELS_PER_MOOD, MAJOR_VERSION, MINOR_VERSION, TIMER_INTERACTIVE_START}, game_state::{KittyArrows, RunType}, menus::{MenuTypes, NormalPlayModes, SelectMenuFocuses}, popup_text::{PopTextRingbuffer, PopupIcon}
     }, title_ss::OUTPUT_ONLINEPNGTOOLS
 };
 
+/// blit a sprite frame at (x, y) on the screen, cut off wherever it leaves the camera's view.
+fn blit_in_view(spritesheet: &[u8], spritesheet_stride: u32, camera: &Camera, frame: &spritesheet::SpriteFrame, x: i32, y: i32, flags: u32) {
+    let (clip_x, clip_y, w, h) = match camera.clip(x, y, frame.width as u32, frame.height as u32) {
+        Some(clipped) => clipped,
+        None => return,
+    };
+    // a flipped sprite gets cut from the other end of where it sits on the spritesheet
+    let src_dx = match flags & BLIT_FLIP_X {
+        0 => clip_x - x,
+        _ => x + frame.width as i32 - (clip_x + w as i32),
+    };
+    let src_dy = match flags & BLIT_FLIP_Y {
+        0 => clip_y - y,
+        _ => y + frame.height as i32 - (clip_y + h as i32),
+    };
+    blit_sub(
+        spritesheet,
+        clip_x,
+        clip_y,
+        w,
+        h,
+        frame.start_x as u32 + src_dx as u32,
+        frame.start_y as u32 + src_dy as u32,
+        spritesheet_stride,
+        flags,
+    );
+}
+
 /// draw the tiles in the map, relative to the camera.
-fn drawmap(game_state: &GameState) {
+fn drawmap(game_state: &GameState, camera: &Camera) {
     let map = &game_state.map;
-    let camera = &game_state.camera;
 
     let tileset = &MAP_TILESETS[game_state.tileset_idx];
 
@@ -50,65 +77,147 @@ fn drawmap(game_state: &GameState) {
                         let chunk_x_offset: i32 = (TILE_WIDTH_PX) as i32 * chunk.bound.x;
                         let chunk_y_offset: i32 = (TILE_HEIGHT_PX) as i32 * chunk.bound.y;
                         let x_loc = (chunk_x_offset + col as i32 * TILE_HEIGHT_PX as i32)
-                            - camera.current_viewing_x_offset as i32;
+                            - (camera.current_viewing_x_offset + camera.shake_x) as i32 + camera.view_x;
                         let y_loc = (chunk_y_offset + row as i32 * TILE_WIDTH_PX as i32)
-                            - camera.current_viewing_y_offset as i32;
-
-                        if x_loc >= 0 - TILE_WIDTH_PX as i32 && x_loc < SCREEN_WIDTH_PX as i32 && y_loc >= 0 - TILE_HEIGHT_PX as i32 && y_loc < SCREEN_HEIGHT_PX as i32 {
-                            blit_sub(
-                                &game_state.spritesheet,
-                                x_loc,
-                                y_loc,
-                                game_state.background_tiles[tile_i].frames[0].width as u32,
-                                game_state.background_tiles[tile_i].frames[0].height as u32,
-                                game_state.background_tiles[tile_i].frames[0].start_x as u32,
-                                game_state.background_tiles[tile_i].frames[0].start_y as u32,
-                                (game_state.spritesheet_stride) as u32,
-                                spritesheet::KITTY_SPRITESHEET_FLAGS,
-                            );
-                        }
+                            - (camera.current_viewing_y_offset + camera.shake_y) as i32 + camera.view_y;
+
+                        blit_in_view(
+                            &game_state.spritesheet,
+                            game_state.spritesheet_stride as u32,
+                            camera,
+                            &game_state.background_tiles[tile_i].frames[0],
+                            x_loc,
+                            y_loc,
+                            spritesheet::KITTY_SPRITESHEET_FLAGS,
+                        );
                     }
                 }
             }
         }
     }
+
+    // WATER: a wavy surface line, and a sprinkle of dots below it.
+    unsafe { *DRAW_COLORS = 0x0002 }
+    for chunk in &map.chunks {
+        let water_top_row = match chunk.water_top_row {
+            Some(row) => row,
+            None => continue,
+        };
+        for row in water_top_row..chunk.bound.height {
+            for col in 0..chunk.bound.width {
+                if chunk.get_tile(col, row) != 0 {
+                    continue;
+                }
+                let x_loc = TILE_WIDTH_PX as i32 * (chunk.bound.x + col as i32) - (camera.current_viewing_x_offset + camera.shake_x) as i32 + camera.view_x;
+                let y_loc = TILE_HEIGHT_PX as i32 * (chunk.bound.y + row as i32) - (camera.current_viewing_y_offset + camera.shake_y) as i32 + camera.view_y;
+                if row == water_top_row {
+                    const WAVE_PERIOD: u32 = 12;
+                    let wave = ((col as u32 + game_state.song_timer / WAVE_PERIOD) % 2) as i32;
+                    if let Some((x, y, w, _)) = camera.clip(x_loc, y_loc + wave, TILE_WIDTH_PX as u32, 1) {
+                        hline(x, y, w);
+                    }
+                } else {
+                    let dot_x = x_loc + 1 + 2 * (row % 2) as i32;
+                    if camera.clip(dot_x, y_loc + 2, 1, 1).is_some() {
+                        line(dot_x, y_loc + 2, dot_x, y_loc + 2);
+                    }
+                }
+            }
+        }
+    }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
 }
 
-static mut GAME_STATE_HOLDER: Option<GameState<'static>> = None;
+/// draw the whole map shrunk down, with where everyone is, from player_idx's perspective, inside their view.
+fn drawminimap(game_state: &GameState, player_idx: usize, camera: &Camera) {
+    const BORDER: f32 = 3.0;
+    // the whole screen leaves room for the HUD, a split view just keeps off its edges
+    let (box_x, box_y, box_w, box_h) = match camera.view_width < SCREEN_WIDTH_PX as u32 || camera.view_height < SCREEN_HEIGHT_PX as u32 {
+        false => (15, 22, 130, 108),
+        true => (camera.view_x + 5, camera.view_y + 5, camera.view_width - 10, camera.view_height - 10),
+    };
+    draw_modal_bg(
+        &AbsoluteBoundingBox {
+            x: box_x as f32 - BORDER,
+            y: box_y as f32 - BORDER,
+            width: box_w as f32 + 2.0 * BORDER,
+            height: box_h as f32 + 2.0 * BORDER,
+        },
+        1,
+        0x0002,
+    );
+    let layout = MinimapLayout::fit(&game_state.map, box_x, box_y, box_w, box_h);
+
+    // chunks are outlined, and filled in once someone has been there
+    for chunk in game_state.map.chunks.iter() {
+        let (x, y, w, h) = layout.bound_to_screen(&chunk.bound);
+        unsafe {
+            *DRAW_COLORS = match chunk.visited {
+                true => 0x0022,
+                false => 0x0020,
+            }
+        }
+        rect(x, y, w, h);
+    }
 
-/// Draw a character on-screen, relative to the camera.
-fn drawcharacter(
-    spritesheet: &[u8],
-    spritesheet_stride: &usize,
-    camera: &Camera,
-    character: MovingEntity,
-) {
-    let the_char: &mut Character;
+    let blink = (game_state.song_timer / 8) % 2 == 0;
 
-    match character {
-        MovingEntity::OptionalPlayer(optionally_enabled_player) => {
-            match optionally_enabled_player {
-                OptionallyEnabledPlayer::Enabled(p) => {
-                    the_char = &mut p.character;
+    // unfound kitties only show up with the reveal card
+    if let OptionallyEnabledPlayer::Enabled(p) = &game_state.players[player_idx] {
+        if p.effects.is_active(EffectTypes::Reveal) && blink {
+            unsafe { *DRAW_COLORS = 0x0003 }
+            for npc in game_state.npcs.iter() {
+                if npc.following_i.is_some() {
+                    continue;
                 }
-                OptionallyEnabledPlayer::Disabled => return,
+                let (x, y) = layout.world_to_screen(npc.x_pos.to_f32(), npc.y_pos.to_f32());
+                rect(x, y, 2, 2);
             }
         }
-        MovingEntity::NPC(npc) => {
-            the_char = npc;
+    }
+
+    // kitties following someone
+    unsafe { *DRAW_COLORS = 0x0004 }
+    for npc in game_state.npcs.iter() {
+        if npc.following_i.is_none() {
+            continue;
+        }
+        let (x, y) = layout.world_to_screen(npc.x_pos.to_f32(), npc.y_pos.to_f32());
+        rect(x, y, 1, 1);
+    }
+
+    // players, with this one blinking
+    unsafe { *DRAW_COLORS = 0x0014 }
+    for (i, opt_p) in game_state.players.iter().enumerate() {
+        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+            if i == player_idx && !blink {
+                continue;
+            }
+            let (x, y) = layout.world_to_screen(p.character.x_pos.to_f32(), p.character.y_pos.to_f32());
+            rect(x - 1, y - 1, 3, 3);
         }
     }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+}
+
+static mut GAME_STATE_HOLDER: Option<GameState<'static>> = None;
 
+/// Draw a character on-screen, relative to the camera.
+fn drawcharacter(
+    spritesheet: &[u8],
+    spritesheet_stride: &usize,
+    camera: &Camera,
+    the_char: &Character,
+) {
     let i = the_char.current_sprite_i as usize;
-    blit_sub(
+    let (x, y) = camera.cvt_world_to_screen_coords(the_char.x_pos.to_f32(), the_char.y_pos.to_f32());
+    blit_in_view(
         &spritesheet,
-        (the_char.x_pos - camera.current_viewing_x_offset) as i32,
-        (the_char.y_pos - camera.current_viewing_y_offset) as i32,
-        the_char.sprite.frames[i].width as u32,
-        the_char.sprite.frames[i].height as u32,
-        the_char.sprite.frames[i].start_x as u32,
-        the_char.sprite.frames[i].start_y as u32,
         *spritesheet_stride as u32,
+        camera,
+        &the_char.sprite.frames[i],
+        x as i32,
+        y as i32,
         spritesheet::KITTY_SPRITESHEET_FLAGS
             | if the_char.is_facing_right {
                 0
@@ -122,8 +231,6 @@ fn drawcharacter(
     );
 }
 
-static mut NPC_INPUTS: [u8; MAX_N_NPCS] = [0; MAX_N_NPCS];
-
 static mut PREVIOUS_GAMEPAD: [u8; 4] = [0, 0, 0, 0];
 
 /// get joystick inputs from this and last frame.
@@ -221,7 +328,17 @@ fn layertext(t: &str, x: i32, y: i32) {
     text(t, x, y);
 }
 
-const TIMER_INTERACTIVE_START: u32 = 100;
+// what each player's text gets drawn in. The 4th player doesn't have a colour left, so they get highlighted.
+const PLAYER_TEXT_COLORS: [u16; 4] = [0x0002, 0x0003, 0x0004, 0x0032];
+
+/// Draw text with a soft background under, in a player's colour
+fn playertext(t: &str, x: i32, y: i32, player_i: usize) {
+    unsafe { *DRAW_COLORS = 0x0001 }
+    text(t, x + 1, y + 1);
+    unsafe { *DRAW_COLORS = PLAYER_TEXT_COLORS[player_i] }
+    text(t, x, y);
+}
+
 const TITLE_Y: i32 = 15;
 
 fn render_title(game_state: &GameState, y: i32) {
@@ -245,10 +362,170 @@ fn render_title(game_state: &GameState, y: i32) {
     unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
 }
 
+/// Draw the map and everything in it, as seen by one camera.
+fn drawworld(game_state: &GameState, camera: &Camera) {
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+
+    // DRAW THE PLAYERS
+    for optional_player in game_state.players.iter() {
+        if let OptionallyEnabledPlayer::Enabled(p) = optional_player {
+            drawcharacter(
+                &game_state.spritesheet,
+                &game_state.spritesheet_stride,
+                camera,
+                &p.character,
+            );
+        }
+    }
+
+    // DRAW NPCS
+    for npc in game_state.npcs.iter() {
+        drawcharacter(
+            &game_state.spritesheet,
+            &game_state.spritesheet_stride,
+            camera,
+            npc,
+        );
+    }
+
+    // DRAW SHADOW CATS (all in the palette's enemy colour)
+    unsafe { *DRAW_COLORS = 0x3330 }
+    for enemy in game_state.enemies.iter() {
+        drawcharacter(
+            &game_state.spritesheet,
+            &game_state.spritesheet_stride,
+            camera,
+            enemy,
+        );
+    }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+
+
+    // DRAW WARP PADS (greyed out until discovered)
+    for pad in game_state.map.warp_pads.iter() {
+        let frame = &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::WarpPad).frames[0];
+        let (x, y) = camera.cvt_world_to_screen_coords(pad.x_pos, pad.y_pos);
+        unsafe {
+            *DRAW_COLORS = match pad.discovered {
+                true => spritesheet::KITTY_SPRITESHEET_DRAW_COLORS,
+                false => 0x2220,
+            }
+        }
+        blit_in_view(
+            &game_state.spritesheet,
+            game_state.spritesheet_stride as u32,
+            camera,
+            frame,
+            x as i32,
+            y as i32,
+            spritesheet::KITTY_SPRITESHEET_FLAGS,
+        );
+    }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+
+    // DRAW PICKUPS (bobbing up and down)
+    for (i, pickup) in game_state.pickups.iter().enumerate() {
+        if pickup.collected {
+            continue;
+        }
+        let bob = (1.5 * num::Float::sin((game_state.song_timer as f32 + i as f32 * 7.0) * 0.1f32)) as i32;
+        let frame = &pickup.sprite.frames[0];
+        let (x, y) = camera.cvt_world_to_screen_coords(pickup.x_pos, pickup.y_pos);
+        blit_in_view(
+            &game_state.spritesheet,
+            game_state.spritesheet_stride as u32,
+            camera,
+            frame,
+            x as i32,
+            y as i32 + bob,
+            spritesheet::KITTY_SPRITESHEET_FLAGS,
+        );
+    }
+
+ 
+    // ------ RENDER THE MAP -----------
+    drawmap(&game_state, camera);
+
+    // DRAW LOCKS ON THE LOCKED DOORS
+    for door in game_state.map.doors.iter() {
+        if !door.locked {
+            continue;
+        }
+        let frame = &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Lock).frames[0];
+        let door_bound = get_bound_of_door(door);
+        let (x, y) = camera.cvt_world_to_screen_coords(
+            (door_bound.x + (door_bound.width as i32 - frame.width as i32) / 2) as f32,
+            (door_bound.y + (door_bound.height as i32 - frame.height as i32) / 2) as f32,
+        );
+        blit_in_view(
+            &game_state.spritesheet,
+            game_state.spritesheet_stride as u32,
+            camera,
+            frame,
+            x as i32,
+            y as i32,
+            spritesheet::KITTY_SPRITESHEET_FLAGS,
+        );
+    }
+
+
+    // DRAW PARTICLES
+    for particle in game_state.particles.alive().iter() {
+        let def = particle.emitter.def();
+        let (x, y) = camera.cvt_world_to_screen_coords(particle.x, particle.y);
+        let (x, y) = (x as i32, y as i32);
+        match def.look {
+            ParticleLook::Cloud => {
+                let cloud_sprite: &spritesheet::Sprite = spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Cloud);
+                blit_in_view(
+                    &game_state.spritesheet,
+                    game_state.spritesheet_stride as u32,
+                    camera,
+                    &cloud_sprite.frames[0],
+                    x,
+                    y,
+                    spritesheet::KITTY_SPRITESHEET_FLAGS
+                        | if particle.vx <= 0.0 {
+                            0
+                        } else {
+                            BLIT_FLIP_X
+                        }
+                        | if particle.vy >= 0.0 {
+                            0
+                        } else {
+                            BLIT_FLIP_Y
+                        }
+                );
+            }
+            ParticleLook::Pixel => {
+                if camera.clip(x, y, 1, 1).is_none() {
+                    continue;
+                }
+                unsafe { *DRAW_COLORS = def.color_i }
+                hline(x, y, 1);
+            }
+            ParticleLook::Sparkle => {
+                unsafe { *DRAW_COLORS = def.color_i }
+                if particle.t < def.lifetime / 2 {
+                    if let Some((hx, hy, w, _)) = camera.clip(x - 1, y, 3, 1) {
+                        hline(hx, hy, w);
+                    }
+                    if let Some((vx, vy, _, h)) = camera.clip(x, y - 1, 1, 3) {
+                        vline(vx, vy, h);
+                    }
+                } else if camera.clip(x, y, 1, 1).is_some() {
+                    hline(x, y, 1);
+                }
+            }
+        }
+    }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+}
+
 /// Main loop that runs every frame. Progress the game state and render.
 #[no_mangle]
 fn update() {
-    let mut game_state: &mut GameState;
+    let game_state: &mut GameState;
 
     // -------- INITIALIZE GAME STATE IF NEEDED ----------
     unsafe {
@@ -262,6 +539,12 @@ fn update() {
                 }
                 
                 new_game_state.regenerate_map();
+
+                // PICK UP WHERE THE SAVE LEFT OFF
+                let mut save_bytes = [0u8; SAVE_SIZE];
+                diskr(save_bytes.as_mut_ptr(), SAVE_SIZE as u32);
+                new_game_state.save = SaveData::from_bytes(&save_bytes);
+
                 GAME_STATE_HOLDER = Some(new_game_state);
             }
             Some(_) => {}
@@ -274,10 +557,22 @@ fn update() {
         }
     }
 
-    // ----------- UPDATE TIMER AND PLAY BGM -----------
-    game_state.song_timer += 1;
-    play_bgm(game_state.song_timer, &SONGS[game_state.song_idx]);
+    // ------------- POLL INPUT ---------------
+    let [btns_pressed_this_frame, gamepads] = get_inputs_this_frame();
+
+    // ------------- STEP THE SIMULATION ---------------
+    game_state.netplay = unsafe { *NETPLAY & 0b100 != 0 };
+    game_state.step(gamepads, btns_pressed_this_frame);
 
+    // ----------- WRITE THE SAVE, SHARE DAILY RESULTS -----------
+    if game_state.save_dirty {
+        let save_bytes = game_state.save.to_bytes();
+        unsafe { diskw(save_bytes.as_ptr(), SAVE_SIZE as u32) };
+        game_state.save_dirty = false;
+    }
+    if let Some(share_text) = game_state.daily.share_text.take() {
+        trace(share_text);
+    }
 
     let mut player_idx: u8 = 0b0;
 
@@ -291,258 +586,63 @@ fn update() {
         }
     }
 
-    // SET CAMERA POSITION
-    match &mut game_state.players[player_idx as usize] {
-        OptionallyEnabledPlayer::Disabled => {}
-        OptionallyEnabledPlayer::Enabled(player) => {
-            game_state.camera.current_viewing_x_target = player.character.x_pos - 80.0;
-            game_state.camera.current_viewing_y_target = player.character.y_pos - 80.0;
-        }
-    }
+    // ----------- PLAY BGM AND SOUND EFFECTS -----------
+    let music_params = game_state.music_params(player_idx as usize);
+    let bgm = game_state.song_player.advance(game_state.song_timer, &SONGS[game_state.song_idx], &music_params);
+    play_bgm(bgm, game_state.sfx.ducked);
+    game_state.sfx.play();
 
-    game_state.camera.slew();
-
-    // ------------- POLL INPUT ---------------
-
-    let [btns_pressed_this_frame, gamepads] = get_inputs_this_frame();
+    // SHAKE THE CAMERAS (only what gets drawn moves)
+    let (shake_x, shake_y) = match game_state.settings.reduced_motion {
+        true => (0.0, 0.0),
+        false => (game_state.camera_effects.shake_x, game_state.camera_effects.shake_y),
+    };
+    for camera in game_state.cameras.iter_mut() {
+        camera.set_shake(shake_x, shake_y);
+    }
 
-    // CHECK IF WE NEED TO FREEZE CHARACTERS / GAMEPLAY ON SCREEN
-    let mut showing_modal = false;
-    match &game_state.game_mode {
-        GameMode::NormalPlay(play_mode) => {
-            
+    render(game_state, player_idx as usize);
+}
 
-            match play_mode {
-                NormalPlayModes::MainGameplay => {
-                    // handle player inputs here
-                    game_state.countdown_paused = false;
-                }
-                NormalPlayModes::HoverModal(_) => {
-                    showing_modal = true;
-                    game_state.countdown_paused = true;
-                }
-            }
-        },
-        _ => {}
-    }
-    // ON TITLE SCREEN, MOVE PLAYER 1 BASED ON TIME
-    
-    // CHECK IF CHARACTERS / CATS ARE COLLIDING
-    if !showing_modal {
-        check_entity_collisions(&mut game_state);
-    }
-    
+/// Draw everything for this frame, from player_idx's perspective. Doesn't change the game state.
+fn render(game_state: &GameState<'static>, player_idx: usize) {
     // PREPARE TO RENDER THE MAP & ENTITIES
+    let mut palette = spritesheet::KITTY_SPRITESHEET_PALETTES[game_state.pallette_idx];
+    // endless mode gets darker the further it goes
+    if let RunType::Endless = game_state.settings.run_type {
+        palette = darkened_palette(palette, endless_tier(game_state.difficulty_level));
+    }
     unsafe {
-        *PALETTE = spritesheet::KITTY_SPRITESHEET_PALETTES[game_state.pallette_idx];
+        *PALETTE = match game_state.settings.reduced_motion {
+            true => palette,
+            false => game_state.camera_effects.flashed_palette(palette),
+        };
     }
     unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
 
-    // MOVE AND RENDER THE PLAYERS 
-    {
-        let optional_players: &mut [OptionallyEnabledPlayer; 4] = &mut game_state.players;
-
-        for (i, optional_player) in &mut optional_players.iter_mut().enumerate() {
-
-
-            let mut input = match false { // showing_modal {
-                false => gamepads[i],
-                true => 0,
-            };
-            if i == 0 {
-                match game_state.game_mode {
-                    GameMode::StartScreen => {
-                        let mut move_n = (((game_state.song_timer / 10) * 31) % 29) as u8;
-                        move_n &= !(BUTTON_LEFT | BUTTON_RIGHT);
-                        input = move_n;
-                        match move_n {
-                            0..=2 => {
-                                input |= BUTTON_LEFT;
-                            },
-                            3..=6=> {
-                                input |= BUTTON_RIGHT;
-                            }
-                            _ => {}
-                        }
-                    },
-                    _ => {},
-                }
-            }
-            
-
-            update_pos(
-                &game_state.map,
-                MovingEntity::OptionalPlayer(optional_player),
-                input,
-                game_state.godmode,
-                &mut game_state.clouds,
-            );
-            
-        
-
-            drawcharacter(
-                &game_state.spritesheet,
-                &game_state.spritesheet_stride,
-                &game_state.camera,
-                MovingEntity::OptionalPlayer(optional_player),
-            );
-        }
-    }
-
-   
-
-
-    // CREATE INPUTS FOR NPCS
-    let inputs: &mut [u8; MAX_N_NPCS] = unsafe { &mut NPC_INPUTS };
-    let l;
-    {
-        l = game_state.npcs.len();
+    // ------ RENDER THE WORLD, ONCE FOR EACH VIEW -----------
+    // each split-screen player gets their own view (and HUD in it), otherwise it's just this player's
+    let split_players = game_state.split_screen_players();
+    let hud_views: Vec<(usize, &Camera)> = match split_players.len() {
+        0 => vec![(player_idx, &game_state.cameras[player_idx])],
+        _ => split_players.iter().map(|&i| (i, &game_state.cameras[i])).collect(),
+    };
+    let views: Vec<&Camera> = hud_views.iter().map(|&(_, camera)| camera).collect();
+    for camera in views.iter() {
+        drawworld(game_state, camera);
     }
-    for i in 0..l {
-        let rng = &mut game_state.rng;
-        let rand_val = (rng.next_for_input() % 255) as u8;
-        let current_npc = &mut game_state.npcs[i];
-        let mut use_rng_input = false;
-        match current_npc.following_i {
-            None => {
-                use_rng_input = true;
-            }
-            Some(p_i) => {
-                let the_opt_player = &game_state.players[p_i as usize];
-                if let OptionallyEnabledPlayer::Enabled(p) = the_opt_player {
-                    let p_bound = get_bound_of_character(&p.character);
-                    let npc_bound: AbsoluteBoundingBox<i32, u32> =
-                        get_bound_of_character(&current_npc);
-                    let needs_teleport;
-                    {
-                        // teleportAyh-shon if needed
-                        const TELEPORT_AXIS_MIN_DIST: u32 = SCREEN_HEIGHT_PX as u32;
-                        if p_bound.x.abs_diff(npc_bound.x) > TELEPORT_AXIS_MIN_DIST
-                            || p_bound.y.abs_diff(npc_bound.y) > TELEPORT_AXIS_MIN_DIST
-                        {
-                            needs_teleport = true
-                        } else {
-                            needs_teleport = false
-                        }
-                    }
-
-                    if needs_teleport {
-                        current_npc.x_pos = p_bound.x as f32;
-                        current_npc.y_pos = p_bound.y as f32;
-                        current_npc.x_vel = 0.0;
-                        current_npc.y_vel = 0.0;
-                    } else {
-                        if rng.next_for_input() % 10 > 1 {
-                            inputs[i] = 0;
-
-                            // if current_npc.x_pos + (npc_bound.width as f32) < p.x_pos {
-                            // else if current_npc.x_pos > p.x_pos + p_bound.width as f32 {
-
-                            // make NPCs tryhard when they're not in the same Y to get to exact x position to help with climbing
-                            let mut tryhard_get_to_0: bool = true;
-                            let ch = &p.character;
-                            // fall by doing nothing
-                            if current_npc.y_pos + (npc_bound.height as f32) < ch.y_pos {
-                            } else if current_npc.y_pos > ch.y_pos + p_bound.height as f32 {
-                                inputs[i] |= BUTTON_1;
-                            } else {
-                                tryhard_get_to_0 = false;
-                            }
-
-                            if tryhard_get_to_0 {
-                                if current_npc.x_pos < ch.x_pos {
-                                    inputs[i] |= BUTTON_RIGHT;
-                                } else if current_npc.x_pos > ch.x_pos {
-                                    inputs[i] |= BUTTON_LEFT;
-                                }
-                            } else {
-                                if current_npc.x_pos + (npc_bound.width as f32) < ch.x_pos {
-                                    inputs[i] |= BUTTON_RIGHT;
-                                } else if current_npc.x_pos > ch.x_pos + p_bound.width as f32
-                                {
-                                    inputs[i] |= BUTTON_LEFT;
-                                }
-                            }
-                        } else {
-                            use_rng_input = true;
-                        }
-                    }
-                } else {
-                    use_rng_input = false;
-                }
-            }
-        }
 
-        if use_rng_input {
-            if rand_val < 20 {
-                inputs[i] = 0x10;
-            } else if rand_val < 40 {
-                inputs[i] = 0x20;
-            } else if rand_val < 42 {
-                inputs[i] = BUTTON_1;
-            } else {
-                inputs[i] = 0x0;
-            }
+    // LINES BETWEEN THE VIEWS
+    unsafe { *DRAW_COLORS = 0x0002 }
+    match views.len() {
+        1 => {}
+        2 => hline(0, SCREEN_HEIGHT_PX as i32 / 2, SCREEN_WIDTH_PX as u32),
+        _ => {
+            hline(0, SCREEN_HEIGHT_PX as i32 / 2, SCREEN_WIDTH_PX as u32);
+            vline(SCREEN_WIDTH_PX as i32 / 2, 0, SCREEN_HEIGHT_PX as u32);
         }
-        
-
-
-    }
-
-    // MOVE NPCS
-    for (i, npc) in game_state.npcs.iter_mut().enumerate() {
-        update_pos(
-            &game_state.map,
-            MovingEntity::NPC(npc),
-            inputs[i],
-            game_state.godmode,
-            &mut game_state.clouds,
-        );
-    }
-
-    // DRAW NPCS
-    for npc in game_state.npcs.iter_mut() {
-        drawcharacter(
-            &game_state.spritesheet,
-            &game_state.spritesheet_stride,
-            &game_state.camera,
-            MovingEntity::NPC(npc),
-        );
-    }
-
- 
-    // ------ RENDER THE MAP -----------
-    drawmap(&game_state);
-
-    // UPDATE CLOUDS
-    Cloud::update_clouds(&mut game_state.clouds);
-
-    // DRAW CLOUDS
-    for cloud in game_state.clouds.iter() {
-        let cam: &Camera = &game_state.camera;
-        let cloud_sprite: &spritesheet::Sprite = spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Cloud);
-        blit_sub(
-            &game_state.spritesheet,
-            (cloud.x - cam.current_viewing_x_offset) as i32,
-            (cloud.y - cam.current_viewing_y_offset) as i32,
-            cloud_sprite.frames[0].width as u32,
-            cloud_sprite.frames[0].height as u32,
-            cloud_sprite.frames[0].start_x as u32,
-            cloud_sprite.frames[0].start_y as u32,
-            game_state.spritesheet_stride as u32,
-            spritesheet::KITTY_SPRITESHEET_FLAGS
-                | if cloud.vx <= 0.0 {
-                    0
-                } else {
-                    BLIT_FLIP_X
-                }
-                | if cloud.vy >= 0.0 {
-                    0
-                } else {
-                    BLIT_FLIP_Y
-                }
-        );
     }
+    unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
 
     // just draw a spriteframe at a location. Put a colored layer behind it, like layertext() does.
     fn draw_spriteframe (spritesheet: &[u8], spriteframe: &spritesheet::SpriteFrame, spritesheet_stride: u32, x: i32, y: i32) {
@@ -564,8 +664,8 @@ fn update() {
         
     }
 
-    // Depending on what gamemode we're in, we do different update steps.
-    match &mut game_state.game_mode {
+    // Depending on what gamemode we're in, we draw different things.
+    match &game_state.game_mode {
         GameMode::NormalPlay(play_mode) => {
             // Draw blur sections for the status bars on the bottom and top of the screen.
             draw_modal_bg(
@@ -593,9 +693,7 @@ fn update() {
 
 
             // COUNT THE NUMBER OF NPCS THAT ARE FOLLOWING PLAYERS
-            let current_found_npcs: u32 = game_state.npcs
-                .iter()
-                .fold(0, |acc, e| acc + match e.following_i {None => 0, Some(_) => 1});
+            let current_found_npcs: u32 = game_state.count_found_npcs();
 
             // COMPUTE SCORE, LEVEL, # KITTIES (used later either in modal or normal screen)
             let world_level_text = &format!["W{}-L{}", ((game_state.difficulty_level - 1) / LEVELS_PER_MOOD as u32) + 1, ((game_state.difficulty_level - 1) % LEVELS_PER_MOOD as u32) + 1];
@@ -606,220 +704,123 @@ fn update() {
                 RunType::Speedrun(n) => {
                     speedrun_seed_text = format!["Sd.{}: {}s", n, game_state.speedrun_timer_msec/ 60];
                 },
+                RunType::Daily => {
+                    speedrun_seed_text = format!["Day #{}", game_state.save.daily_day];
+                },
                 _ => {}
             };
             let found_kitties_text = &format!["{:.2}/{:.2}", current_found_npcs, game_state.total_npcs_to_find];
             let time_left_text = &format!["{:<3}", game_state.countdown_timer_msec as u32 / 60];
 
-            // UPDATE & DRAW POPUPS
+            // DRAW POPUPS
             {
-                let popup_texts_rb: &mut PopTextRingbuffer = &mut game_state.popup_text_ringbuffer;
-
-
-                popup_texts_rb.update_popup_positions();
-                
-                
-
-                let camera = game_state.camera;
-                for popup in popup_texts_rb.texts.iter() {
-                    match popup {
-                        Some(p) => {
-                            const T_BEFORE_BLINK: u32 = 60;
-                            if p.duration_timer < T_BEFORE_BLINK || p.duration_timer % 6 < 3 {
-                                let (dx, dy) = ((p.x_pos - camera.current_viewing_x_offset) as i32, (p.y_pos - camera.current_viewing_y_offset) as i32);
-                                layertext(&p.text, dx, dy);
-                                match p.icon {
-                                    PopupIcon::None => {},
-                                    PopupIcon::Clock => {
-                                        match game_state.settings.run_type {
-                                  
-                                            RunType::TimedMode => {
-                                                draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, dx, dy-1);
-                                            },
-                                            _ => {
-                                                layertext("Sc", dx-8, dy);
-                                            },
-                                        }
-                                    }
-                                    PopupIcon::CatHead => {
-                                        draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::CatHead).frames[0], game_state.spritesheet_stride as u32, dx+1, dy+1)
-                                    },
-                                    PopupIcon::DownArrow => {
-                                        text([b'\x87'], dx+40, dy);
+                let popup_texts_rb: &PopTextRingbuffer = &game_state.popup_text_ringbuffer;
+
+                // in every view they show up in
+                for camera in views.iter() {
+                    for popup in popup_texts_rb.texts.iter() {
+                        match popup {
+                            Some(p) => {
+                                const T_BEFORE_BLINK: u32 = 60;
+                                if p.duration_timer < T_BEFORE_BLINK || p.duration_timer % 6 < 3 {
+                                    let (dx, dy) = camera.cvt_world_to_screen_coords(p.x_pos, p.y_pos);
+                                    let (dx, dy) = (dx as i32, dy as i32);
+                                    if !camera.can_draw(dx, dy, 8 * p.text.len() as u32, 8) {
+                                        continue;
                                     }
-                                }
-                            }
-                        },
-                        None => {
-
-                        }
-                    }
-                }
-            }
-
-            // USE ABILITY CARDS
-            if !showing_modal {
-                for (p_i, pr) in game_state.players.iter_mut().enumerate() {
-                    match pr {
-                        OptionallyEnabledPlayer::Enabled(p) => {
-                            if !showing_modal && btns_pressed_this_frame[p_i] & BUTTON_2 != 0 {
-                                let res = p.card_stack.try_use_cards();
-                                let added_t;
-                                let popup_t: Option<String>;
-                                let popup_icon: PopupIcon;
-                                match res {
-                                    game::ability_cards::AbilityCardUsageResult::NothingHappened => {
-                                        added_t = 0;
-                                        popup_t = None;
-                                        popup_icon = PopupIcon::None;
-                                    },
-                                    game::ability_cards::AbilityCardUsageResult::GainedTime(t) => {
-                                        added_t = t;
-                                        popup_t = Some(format![" +{}", t]);
-                                        popup_icon = PopupIcon::Clock;
-                                    },
-                                    game::ability_cards::AbilityCardUsageResult::EnabledFlyAndTime(t) => {
-                                        if p.character.can_fly {
-                                            added_t = t;
-                                            popup_t = Some(format![" +{}", t]);
-                                            popup_icon = PopupIcon::Clock;
-                                        } else {
-                                            p.character.can_fly = true;
-                                            added_t = t;
-                                            popup_t = Some("fly!".to_string());
-                                            popup_icon = PopupIcon::None;
+                                    layertext(&p.text, dx, dy);
+                                    match p.icon {
+                                        PopupIcon::None => {},
+                                        PopupIcon::Clock => {
+                                            match game_state.settings.run_type {
+                                  
+                                                RunType::TimedMode | RunType::Daily | RunType::Endless => {
+                                                    draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, dx, dy-1);
+                                                },
+                                                _ => {
+                                                    layertext("Sc", dx-8, dy);
+                                                },
+                                            }
                                         }
-                                        
-                                    },
-                                    game::ability_cards::AbilityCardUsageResult::EnabledWarpAndTime(t) => {
-                                        if p.character.warp_ability == WarpAbility::CannotWarp {
-                                            p.character.warp_ability = WarpAbility::CanWarp(WarpState::Charging(0));
-                                            added_t = t - 10;
-                                            popup_t = Some("hold   : warp".to_string());
-                                            popup_icon = PopupIcon::DownArrow;
-                                        } else {
-                                            added_t = 10;
-                                            popup_t = Some(format![" +{}", t]);
-                                            popup_icon = PopupIcon::Clock;
+                                        PopupIcon::CatHead => {
+                                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::CatHead).frames[0], game_state.spritesheet_stride as u32, dx+1, dy+1)
+                                        },
+                                        PopupIcon::DownArrow => {
+                                            text([b'\x87'], dx+40, dy);
                                         }
+                                        PopupIcon::Fish => {
+                                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::FishPickup).frames[0], game_state.spritesheet_stride as u32, dx, dy+1)
+                                        },
+                                        PopupIcon::Key => {
+                                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::KeyPickup).frames[0], game_state.spritesheet_stride as u32, dx, dy+2)
+                                        },
+                                        PopupIcon::CardPack => {
+                                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::CardPackPickup).frames[0], game_state.spritesheet_stride as u32, dx, dy)
+                                        },
                                     }
                                 }
-                                match popup_t {
-                                    Some(pt) => {
-                                        // spawn some clouds
-                                        for dir in [(1.0, 0.0), (0.5, 0.86), (-0.5, 0.86), (-1.0, 0.0), (-0.5, -0.86), (0.5, -0.86)] {
-                                            const CARD_CLOUD_SPEED: f32 = 4.0;
-
-                                            let vx = CARD_CLOUD_SPEED * dir.0;
-                                            let vy = CARD_CLOUD_SPEED * dir.1;
-                                            Cloud::try_push_cloud(&mut game_state.clouds, p.character.x_pos + 2.0, p.character.y_pos + 3.0, vx, vy);
+                            },
+                            None => {
 
-                                        }
-                                        game_state.popup_text_ringbuffer.add_new_popup(p.character.x_pos - 14.0, p.character.y_pos, pt, popup_icon);
-                                    }
-                                    _ => {}
-                                }
-                                game_state.countdown_timer_msec += added_t * 60;
-                                game_state.countdown_timer_msec = game_state.countdown_timer_msec.min(100 * 60 - 1);
-                                game_state.score += added_t;
                             }
-                        },
-                        OptionallyEnabledPlayer::Disabled => {},
-                    }
-                }
-                
-            }
-
-            // MOVE ABILITY CARD POSITIONS
-            match &mut game_state.players[player_idx as usize] {
-                OptionallyEnabledPlayer::Enabled(p) => {
-                    for (i, card) in p.card_stack.cards.iter_mut().enumerate() {
-                        match card {
-                            Some(c) => {
-                                c.target_x = (80 + 15 * i) as f32;
-                                c.target_y = 1.0;
-                            },
-                            None => {}
                         }
                     }
-                    p.card_stack.move_cards();
-                },
-                OptionallyEnabledPlayer::Disabled => {}
+                }
             }
 
             
-            // DRAW ABILITY CARDS
+            // DRAW ABILITY CARDS (they're kept in view coordinates)
             unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
-            match &game_state.players[player_idx as usize] {
-                OptionallyEnabledPlayer::Enabled(p) => {
-                    for card in p.card_stack.cards.iter() {
-                        match &card {
+            for &(hud_p_i, camera) in hud_views.iter() {
+                match &game_state.players[hud_p_i] {
+                    OptionallyEnabledPlayer::Enabled(p) => {
+                        for card in p.card_stack.cards.iter() {
+                            match &card {
+                                
+                                Some(c) => {
+                                    // trace(&format!["{}", i]);
+                                    blit_in_view(
+                                        &game_state.spritesheet,
+                                        game_state.spritesheet_stride as u32,
+                                        camera,
+                                        &c.sprite.frames[0],
+                                        camera.view_x + c.x_pos.to_int(),
+                                        camera.view_y + c.y_pos.to_int(),
+                                        spritesheet::KITTY_SPRITESHEET_FLAGS,
+                                    );
+                                },
+                                None => {},
+                            }
                             
-                            Some(c) => {
-                                // trace(&format!["{}", i]);
-                                blit_sub(
-                                    &game_state.spritesheet,
-                                    c.x_pos as i32,
-                                    c.y_pos as i32,
-                                    c.sprite.frames[0].width as u32,
-                                    c.sprite.frames[0].height as u32,
-                                    c.sprite.frames[0].start_x as u32,
-                                    c.sprite.frames[0].start_y as u32,
-                                    (game_state.spritesheet_stride) as u32,
-                                    spritesheet::KITTY_SPRITESHEET_FLAGS,
-                                );
-                            },
-                            None => {},
                         }
-                        
-                    }
-                },
-                OptionallyEnabledPlayer::Disabled => {},
+
+                        // name the combo that's ready, flashing
+                        match p.card_stack.current_combo() {
+                            Some(recipe) => {
+                                if (game_state.song_timer / 8) % 2 == 0 {
+                                    layertext(recipe.name, camera.view_x + camera.view_width as i32 - 80, camera.view_y + 19);
+                                }
+                                unsafe { *DRAW_COLORS = spritesheet::KITTY_SPRITESHEET_DRAW_COLORS }
+                            }
+                            None => {}
+                        }
+                    },
+                    OptionallyEnabledPlayer::Disabled => {},
+                }
             }
 
             // SHOW MODAL DIALOGS
-            if showing_modal {
-                match play_mode {
-                    NormalPlayModes::MainGameplay => {
-                        unreachable!()
-                    }
-                    NormalPlayModes::HoverModal(m) => {
-                        let mut options_ready_to_select: bool = false;
-                        
-                        let ready_to_show_text;
-                        {
-                            let actual_position: &mut AbsoluteBoundingBox<f32, f32> = &mut m.actual_position;
-                            let target_position: &mut AbsoluteBoundingBox<i32, u32> = &mut m.target_position;
-
-                            const SPEED: f32 = 0.15;
-                            const TOL: f32 = 10.0;
-
-                            let real_tpy = target_position.y + (4f32 * num::Float::sin(game_state.song_timer as f32 * 0.05f32)) as i32;
-
-                            actual_position.x += (target_position.x as f32 - actual_position.x) * SPEED;
-                            actual_position.y += (real_tpy as f32 - actual_position.y) * SPEED;
-                            actual_position.width += (target_position.width as f32 - actual_position.width) * SPEED;
-                            actual_position.height += (target_position.height as f32 - actual_position.height) * SPEED;
-
-                            ready_to_show_text = (actual_position.width - target_position.width as f32).abs() < TOL;
-
-                            draw_modal_bg(&actual_position, 1, 0x0002);
-                        
-                        }
+            match play_mode {
+                NormalPlayModes::HoverModal(m) => {
+                    {
+                        draw_modal_bg(&m.actual_position, 1, 0x0002);
 
-                        let mut text_timer = 0;
-                        {
-                            m.timer += 1;
-                        }
-                        const INTERACTIVE_DELAY: u32 = 60;
-                        if ready_to_show_text && m.timer >= INTERACTIVE_DELAY {
-                            {
-                                options_ready_to_select = true;
-                                text_timer = m.timer;
+                        let ready_to_show_text = m.ready_to_show_text();
+                        let text_timer = match m.ready_to_select() {
+                            true => m.timer,
+                            false => 0,
+                        };
 
-                            }
-                        }
-                        
                         let modal_text = |st: &str, x, y| {
                             unsafe {*DRAW_COLORS = 0x0002}
                             text(st, m.actual_position.x as i32 + x, m.actual_position.y as i32 + y);
@@ -832,44 +833,41 @@ fn update() {
                         
 
                         if ready_to_show_text {
-                            // let cursor_opt: u8;
-                            let mut btn_pressed: bool = false;
-                            if options_ready_to_select {
-                                // cursor_opt = *option;
-                                if btns_pressed_this_frame[0] & (BUTTON_1 | BUTTON_2) != 0 {
-                                    btn_pressed = true
-                                }
-                            }
                             match m.menu_type {
                                 MenuTypes::WonLevel => {
                                     const BLINK_START: u32 = 50;
                                     const BLINK_TITLE_PERIOD: u32 = 17;
                                     if text_timer < BLINK_START || (text_timer / BLINK_TITLE_PERIOD) % 2 == 0 {
                                         // modal_text("Found!!", 12, 15);
-                                        modal_text(world_level_text, 16, 12);
-                                        modal_text("Clear!", 16, 22);
-
-
+                                        match game_state.settings.run_type {
+                                            RunType::Versus(_) => {
+                                                modal_text(&format!["Round {}", game_state.versus.rounds_played], 16, 12);
+                                                match game_state.versus.last_round_winner {
+                                                    Some(i) => {
+                                                        let (xx, yy) = modal_offs(16, 22);
+                                                        playertext(&format!["P{} wins!", i + 1], xx, yy, i);
+                                                    }
+                                                    None => modal_text("Draw!", 16, 22),
+                                                }
+                                            }
+                                            _ => {
+                                                modal_text(world_level_text, 16, 12);
+                                                modal_text("Clear!", 16, 22);
+                                            }
+                                        }
                                     }
 
-                                    match btn_pressed {
-                                        true => {
-                                            game_state.difficulty_level += 1;
-                                            // game_state.game_mode =
-                                            //     GameMode::NormalPlay(NormalPlayModes::MainGameplay);
-                                            game_state.game_mode =
-                                                GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
-                                                    AbsoluteBoundingBox {
-                                                        x: 45,
-                                                        y: 40,
-                                                        width: 70,
-                                                        height: 50,
-                                                    },
-                                                    MenuTypes::StartLevel,
-                                                )));
-                                            game_state.regenerate_map();
-                                        }
-                                        _ => {}
+                                    // TALLY OF PICKUPS FOUND THIS LEVEL
+                                    let tallies = &game_state.level_tallies;
+                                    for (i, (preset, n)) in [
+                                        (spritesheet::PresetSprites::Clock, tallies.clocks),
+                                        (spritesheet::PresetSprites::FishPickup, tallies.fish),
+                                        (spritesheet::PresetSprites::KeyPickup, tallies.keys),
+                                        (spritesheet::PresetSprites::CardPackPickup, tallies.card_packs),
+                                    ].iter().enumerate() {
+                                        let (xx, yy) = modal_offs(4 + 19 * i as i32, 36);
+                                        draw_spriteframe(&game_state.spritesheet, &spritesheet::Sprite::from_preset(preset).frames[0], game_state.spritesheet_stride as u32, xx, yy);
+                                        modal_text(&format!["{}", n], 4 + 19 * i as i32 + 9, 36);
                                     }
                                 },
                                 MenuTypes::StartLevel => {
@@ -880,30 +878,13 @@ fn update() {
 
                                     match game_state.settings.run_type {
                                   
-                                        RunType::TimedMode => {
+                                        RunType::TimedMode | RunType::Daily | RunType::Endless => {
                                             draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, xx, yy);
                                         },
                                         _ => {
                                             modal_text("Sc", 33-2*8, 35);
                                         },
                                     }
-
-                                    let mut start_normal_play = false;
-                                    if text_timer > 100 {
-                                        start_normal_play = true;
-                                    }
-
-
-                                    match btn_pressed {
-                                        true => {
-                                            start_normal_play = true; 
-                                        }
-                                        _ => {}
-                                    }
-                                    if start_normal_play {
-                                        game_state.game_mode =
-                                            GameMode::NormalPlay(NormalPlayModes::MainGameplay);
-                                    }
                                 },
                                 MenuTypes::Done => {
                                     const BLINK_START: u32 = 50;
@@ -914,15 +895,30 @@ fn update() {
 
                                     modal_text(&format!["End: {}", world_level_text], 8, 30);
                                     modal_text(&score_text, 8, 40);
-                                    
+                                    if let RunType::Daily = game_state.settings.run_type {
+                                        modal_text(&speedrun_seed_text, 8, 50);
+                                    }
 
-                                    match btn_pressed {
-                                        true => {
-                                            game_state.difficulty_level = START_DIFFICULTY_LEVEL;
-                                            game_state.game_mode = GameMode::StartScreen;
+                                    // THE ENDLESS LEADERBOARD, WITH THIS RUN HIGHLIGHTED
+                                    if let RunType::Endless = game_state.settings.run_type {
+                                        const N_SHOWN: usize = 3;
+                                        modal_text("Best runs:", 8, 54);
+                                        for (place, entry) in game_state.save.endless_leaderboard.iter().take(N_SHOWN).enumerate() {
+                                            if entry.level == 0 {
+                                                continue;
+                                            }
+                                            let (xx, yy) = modal_offs(8, 66 + 10 * place as i32);
+                                            unsafe {
+                                                *DRAW_COLORS = match game_state.endless.place == Some(place) {
+                                                    true => 0x0004,
+                                                    false => 0x0002,
+                                                }
+                                            }
+                                            let level = entry.level as usize;
+                                            text(&format!["{}.W{}-L{} {}p", place + 1, (level - 1) / LEVELS_PER_MOOD + 1, (level - 1) % LEVELS_PER_MOOD + 1, entry.score], xx, yy);
                                         }
-                                        _ => {}
                                     }
+                                    
                                 },
                                 MenuTypes::WonGame => {
                                     const BLINK_START: u32 = 50;
@@ -935,19 +931,23 @@ fn update() {
                                     modal_text(&score_text, 8, 40);
 
                                     match game_state.settings.run_type {
-                                        RunType::Speedrun(_) => {
+                                        RunType::Speedrun(_) | RunType::Daily => {
                                             modal_text(&speedrun_seed_text, 8, 50);
                                         }
                                         _ => {
                                         }
                                     }
+                                },
+                                MenuTypes::MatchOver => {
+                                    const BLINK_START: u32 = 50;
+                                    const BLINK_TITLE_PERIOD: u32 = 17;
+                                    if text_timer < BLINK_START || (text_timer / BLINK_TITLE_PERIOD) % 2 == 0 {
+                                        modal_text("MATCH OVER!", 21, 10);
+                                    }
 
-                                    match btn_pressed {
-                                        true => {
-                                            game_state.difficulty_level = START_DIFFICULTY_LEVEL;
-                                            game_state.game_mode = GameMode::StartScreen;
-                                        }
-                                        _ => {}
+                                    for (place, (i, wins)) in game_state.versus.standings(&game_state.players).iter().enumerate() {
+                                        let (xx, yy) = modal_offs(12, 28 + 10 * place as i32);
+                                        playertext(&format!["{} P{}: {} wins", place + 1, i + 1, wins], xx, yy, *i);
                                     }
                                 },
                                 MenuTypes::StartGameMessage => {
@@ -975,133 +975,207 @@ fn update() {
                                     modal_text(" = # kittes", 28, 62);
 
                                     match game_state.settings.run_type {
-                                        RunType::TimedMode => {
+                                        RunType::TimedMode | RunType::Daily | RunType::Endless => {
                                             draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, xx+20, yy+78);
                                             modal_text(" = time left", 28, 78);
                                         }
                                         _ => {}
                                       
                                     }
+                                }
+                            }
 
-                                    match btn_pressed {
-                                        true => {
-                                            game_state.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
+                            // RANK EVERYONE AT THE BOTTOM OF THE RESULTS
+                            match m.menu_type {
+                                MenuTypes::WonLevel | MenuTypes::WonGame | MenuTypes::Done => {
+                                    let ranking = rank_players(&game_state.players);
+                                    if ranking.len() > 1 {
+                                        const ROW_HEIGHT: i32 = 10;
+                                        let top = m.actual_position.height as i32 - ROW_HEIGHT * ranking.len() as i32 - 2;
+                                        for (place, (i, stats)) in ranking.iter().enumerate() {
+                                            let (xx, yy) = modal_offs(8, top + ROW_HEIGHT * place as i32);
+                                            let tiles = stats.distance_px as u32 / TILE_WIDTH_PX as u32;
+                                            playertext(&format!["{} P{} {}k {}c {}m", place + 1, i + 1, stats.kitties_found, stats.cards_used, tiles], xx, yy, *i);
                                         }
-                                        _ => {}
                                     }
                                 }
-                            }     
+                                _ => {}
+                            }
                         }
                     }
                 }
-            } else {
-
-                // HELP TEXT AT START OF GAME
-                if game_state.difficulty_level == 1 && game_state.countdown_timer_msec == COUNTDOWN_TIMER_START - 1 && game_state.tutorial_text_counter == 0 {
-                    game_state.tutorial_text_counter += 1;
-                    game_state.game_mode = GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
-                        AbsoluteBoundingBox {
-                            x: 10,
-                            y: 10,
-                            width: 140,
-                            height: 140,
+                NormalPlayModes::MainGameplay => {
+                    // DRAW SCORE, LEVEL, # KITTIES during normal play
+                    layertext(world_level_text, 0, BOTTOM_UI_TEXT_Y);
+                    layertext(&score_text, 60, BOTTOM_UI_TEXT_Y);
+                    layertext(found_kitties_text, 9, TOP_UI_TEXT_Y);
+                    match game_state.settings.run_type {
+                        RunType::TimedMode | RunType::Daily | RunType::Endless => {
+                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, 48, TOP_UI_TEXT_Y - 1);
+                            layertext(time_left_text, 9 + 6*8, TOP_UI_TEXT_Y);
                         },
-                        MenuTypes::StartGameMessage
-                    )));
-                }
-                
 
-                // ------- LEVEL WIN CONDITION -----------
-                if game_state.total_npcs_to_find == current_found_npcs {
-                    if game_state.difficulty_level == FINAL_LEVEL {
-                        game_state.game_mode =
-                        GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
-                            AbsoluteBoundingBox {
-                                x: 25,
-                                y: 35,
-                                width: 110,
-                                height: 65,
-                            },
-                            MenuTypes::WonGame
-                        )));
-                        game_state.song_idx = 0;
-                    } else {
-                        game_state.game_mode =
-                        GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
-                            AbsoluteBoundingBox {
-                                x: 40,
-                                y: 40,
-                                width: 80,
-                                height: 40,
-                            },
-                            MenuTypes::WonLevel
-                        )));
-                        game_state.song_idx = 0;
-                    }
-
-                    game_state.song_timer = 0;
-                }
-
-                // PROGRESS TIME, CHECK FOR GAME END
-                if !game_state.countdown_paused {
-                    game_state.speedrun_timer_msec += 1;
-                    game_state.countdown_timer_msec -= 1;
+                        RunType::Speedrun(_) => {
+                            layertext(&speedrun_seed_text, 1, TOP_UI_TEXT_Y + 10);
+                        },
                     
                     
-            
-                    // ---- LOSE CONDITION ----
-                    match game_state.settings.run_type {
-                        
-                        RunType::TimedMode => {
-                            if game_state.countdown_timer_msec <= 0 {
-            
-                                game_state.song_idx = 0;
-                    
-                                game_state.game_mode = GameMode::NormalPlay(NormalPlayModes::HoverModal(Modal::new(
-                                    AbsoluteBoundingBox {
-                                        x: 15,
-                                        y: 50,
-                                        width: 130,
-                                        height: 60,
-                                    },
-                                    MenuTypes::Done
-                                )));
-                            }
-                        },
                         _ => {}
                     }
-                 
-                }
+                    draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::CatHead).frames[0], game_state.spritesheet_stride as u32, 1, TOP_UI_TEXT_Y + 1);
+
+                    // DRAW ARROWS AT THE EDGE OF EACH VIEW TOWARDS THE NEAREST UNFOUND KITTIES (assist)
+                    // The reveal card already points at all of them.
+                    for &(hud_p_i, camera) in hud_views.iter() {
+                        let p = match &game_state.players[hud_p_i] {
+                            OptionallyEnabledPlayer::Enabled(p) => p,
+                            OptionallyEnabledPlayer::Disabled => continue,
+                        };
+                        if !game_state.settings.show_kitty_arrows() || p.effects.is_active(EffectTypes::Reveal) {
+                            continue;
+                        }
+                        const N_ARROWS: usize = 3;
+                        const GLYPH_SIZE: f32 = 8.0;
+                        const EDGE_MARGIN: f32 = 2.0;
+                        let (left, top) = (camera.view_x as f32, camera.view_y as f32);
+                        let (right, bottom) = (left + camera.view_width as f32, top + camera.view_height as f32);
+
+                        // (distance, screen x, screen y) of every unfound kitty outside the view
+                        let mut offscreen: Vec<(f32, f32, f32)> = Vec::new();
+                        for npc in game_state.npcs.iter() {
+                            if npc.following_i.is_some() {
+                                continue;
+                            }
+                            let (sx, sy) = camera.cvt_world_to_screen_coords(npc.x_pos.to_f32(), npc.y_pos.to_f32());
+                            if sx >= left && sx < right && sy >= top && sy < bottom {
+                                continue;
+                            }
+                            let (dx, dy) = ((npc.x_pos - p.character.x_pos).to_f32(), (npc.y_pos - p.character.y_pos).to_f32());
+                            offscreen.push((num::Float::sqrt(dx * dx + dy * dy), sx, sy));
+                        }
+                        offscreen.sort_by(|a, b| a.0.total_cmp(&b.0));
+                        offscreen.truncate(N_ARROWS);
+
+                        unsafe { *DRAW_COLORS = 0x0004 }
+                        for (dist, sx, sy) in offscreen {
+                            // further kitties fade out, by blinking on for less of the time
+                            const FADE_DIST: f32 = 300.0;
+                            const FADE_STEPS: u32 = 4;
+                            let shown_steps = FADE_STEPS - ((dist / FADE_DIST) as u32).min(FADE_STEPS - 1);
+                            if (game_state.song_timer / 4) % FADE_STEPS >= shown_steps {
+                                continue;
+                            }
+                            let (dx, dy) = (sx - (left + right) / 2.0, sy - (top + bottom) / 2.0);
+                            let glyph = match (dx.abs() > dy.abs(), dx > 0.0, dy > 0.0) {
+                                (true, true, _) => b'\x85',
+                                (true, false, _) => b'\x84',
+                                (false, _, true) => b'\x87',
+                                (false, _, false) => b'\x86',
+                            };
+                            let ex = num::clamp(sx - GLYPH_SIZE / 2.0, left + EDGE_MARGIN, right - GLYPH_SIZE - EDGE_MARGIN);
+                            let ey = num::clamp(sy - GLYPH_SIZE / 2.0, top + EDGE_MARGIN, bottom - GLYPH_SIZE - EDGE_MARGIN);
+                            text([glyph], ex as i32, ey as i32);
+                        }
+                    }
 
+                    // DRAW ARROWS AT THE EDGE OF EACH VIEW TOWARDS UNFOUND KITTIES (reveal card)
+                    for &(hud_p_i, camera) in hud_views.iter() {
+                        let p = match &game_state.players[hud_p_i] {
+                            OptionallyEnabledPlayer::Enabled(p) => p,
+                            OptionallyEnabledPlayer::Disabled => continue,
+                        };
+                        if !p.effects.is_active(EffectTypes::Reveal) {
+                            continue;
+                        }
+                        const EDGE_MARGIN: f32 = 3.0;
+                        let (left, top) = (camera.view_x as f32, camera.view_y as f32);
+                        let (right, bottom) = (left + camera.view_width as f32, top + camera.view_height as f32);
+                        unsafe { *DRAW_COLORS = 0x0004 }
+                        for npc in game_state.npcs.iter() {
+                            if npc.following_i.is_some() {
+                                continue;
+                            }
+                            let (sx, sy) = camera.cvt_world_to_screen_coords(npc.x_pos.to_f32(), npc.y_pos.to_f32());
+                            let ex = num::clamp(sx, left + EDGE_MARGIN, right - EDGE_MARGIN);
+                            let ey = num::clamp(sy, top + EDGE_MARGIN, bottom - EDGE_MARGIN);
+                            if ex == sx && ey == sy {
+                                // already in view
+                                continue;
+                            }
+                            // point from the view's center out towards the kitty
+                            let (dx, dy) = (sx - (left + right) / 2.0, sy - (top + bottom) / 2.0);
+                            let len = num::Float::sqrt(dx * dx + dy * dy);
+                            const ARROW_LEN: f32 = 6.0;
+                            line(ex as i32, ey as i32, (ex - ARROW_LEN * dx / len) as i32, (ey - ARROW_LEN * dy / len) as i32);
+                            rect(ex as i32 - 1, ey as i32 - 1, 3, 3);
+                        }
+                    }
 
+                    // DRAW ACTIVE CARD EFFECTS, EACH WITH A BAR OF HOW MUCH TIME IS LEFT
+                    // (stacking up in rows when they don't fit across the view)
+                    for &(hud_p_i, camera) in hud_views.iter() {
+                        let p = match &game_state.players[hud_p_i] {
+                            OptionallyEnabledPlayer::Enabled(p) => p,
+                            OptionallyEnabledPlayer::Disabled => continue,
+                        };
+                        let per_row = (camera.view_width as usize - 1) / 14;
+                        for (i, effect) in p.effects.effects.iter().enumerate() {
+                            let x = camera.view_x + 1 + 14 * (i % per_row) as i32;
+                            let y = camera.view_y + camera.view_height as i32 - 25 - 16 * (i / per_row) as i32;
+                            draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&effect.effect_type.icon()).frames[0], game_state.spritesheet_stride as u32, x, y);
+                            const BAR_W: u32 = 12;
+                            let bar_w = (BAR_W * effect.frames_left as u32 + effect.full_frames as u32 - 1) / effect.full_frames as u32;
+                            unsafe { *DRAW_COLORS = 0x0001 }
+                            rect(x, y + 13, BAR_W, 2);
+                            unsafe { *DRAW_COLORS = 0x0004 }
+                            rect(x, y + 13, bar_w, 2);
+                        }
+                    }
 
-                // DRAW SCORE, LEVEL, # KITTIES during normal play
-                layertext(world_level_text, 0, BOTTOM_UI_TEXT_Y);
-                layertext(&score_text, 60, BOTTOM_UI_TEXT_Y);
-                layertext(found_kitties_text, 9, TOP_UI_TEXT_Y);
-                match game_state.settings.run_type {
-                    RunType::TimedMode => {
-                        draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::Clock).frames[0], game_state.spritesheet_stride as u32, 48, TOP_UI_TEXT_Y - 1);
-                        layertext(time_left_text, 9 + 6*8, TOP_UI_TEXT_Y);
-                    },
+                    // DRAW # KEYS HELD (only once there are any)
+                    let mut keys_held: u32 = 0;
+                    for opt_p in game_state.players.iter() {
+                        if let OptionallyEnabledPlayer::Enabled(p) = opt_p {
+                            keys_held += p.keys as u32;
+                        }
+                    }
+                    if keys_held > 0 {
+                        draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::KeyPickup).frames[0], game_state.spritesheet_stride as u32, 136, TOP_UI_TEXT_Y + 2);
+                        layertext(&format!["{}", keys_held], 145, TOP_UI_TEXT_Y);
+                    }
 
-                    RunType::Speedrun(_) => {
-                        layertext(&speedrun_seed_text, 1, TOP_UI_TEXT_Y + 10);
-                    },
-                    
-                    
-                    _ => {}
-                }
-                draw_spriteframe(&game_state.spritesheet,  &spritesheet::Sprite::from_preset(&spritesheet::PresetSprites::CatHead).frames[0], game_state.spritesheet_stride as u32, 1, TOP_UI_TEXT_Y + 1);
+                    // DRAW EACH PLAYER'S OWN # KITTIES IN THEIR VIEW, IN THEIR COLOUR (when there's more than one)
+                    if rank_players(&game_state.players).len() > 1 {
+                        for &(hud_p_i, camera) in hud_views.iter() {
+                            let p = match &game_state.players[hud_p_i] {
+                                OptionallyEnabledPlayer::Enabled(p) => p,
+                                OptionallyEnabledPlayer::Disabled => continue,
+                            };
+                            // in versus, it's who has the most kitties right now that matters
+                            let tally = match game_state.settings.run_type {
+                                RunType::Versus(_) => VersusMatch::followers(&game_state.npcs)[hud_p_i],
+                                _ => p.stats.kitties_found,
+                            };
+                            // right-aligned, with room for the shadow, a row under the keys
+                            let tally_text = format!["P{}:{}", hud_p_i + 1, tally];
+                            let x = camera.view_x + camera.view_width as i32 - 8 * tally_text.len() as i32 - 1;
+                            playertext(&tally_text, x, camera.view_y + TOP_UI_TEXT_Y + 10, hud_p_i);
+                        }
+                    }
 
-                
+                    // DRAW THE MINIMAPS (toggled with up + card button)
+                    for &(hud_p_i, camera) in hud_views.iter() {
+                        if game_state.minimap_shown[hud_p_i] {
+                            drawminimap(game_state, hud_p_i, camera);
+                        }
+                    }
+                }
             }
         }
         GameMode::StartScreen => {
             
             
-            // SETUP TITLE MUSIC AND COLORS
-            game_state.song_idx = 1;
+            // SETUP TITLE COLORS
             unsafe { *DRAW_COLORS = 0x0002 }
 
             // SHOW TITLE-SCREEN SUBTEXT
@@ -1124,16 +1198,10 @@ fn update() {
                 text("by CanyonTurtle", 20, 125);
                 text(" & BurntSugar  ", 20, 135);
                 text(format!["ver. {}.{}.{}", MAJOR_VERSION, MINOR_VERSION, INCR_VERSION], 40, 150);
-                if btns_pressed_this_frame[0] != 0 {
-                    // game_state.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
-                    game_state.game_mode = GameMode::SelectScreen(SelectSetup{current_selection: SelectMenuFocuses::RunType});
-                    // game_state.regenerate_map();
-                }
             }
             
 
             render_title(&game_state, TITLE_Y);
-            game_state.rng.next_for_input();
             
             // trace("updated positions");
             unsafe { *DRAW_COLORS = 0x1112 }
@@ -1144,12 +1212,16 @@ fn update() {
             const BOX_LEFT_MARGIN: i32 = 15;
             const BOX_RIGHT_MARGIN: i32 = BOX_LEFT_MARGIN;
             const BOX_WIDTH: i32 = SCREEN_WIDTH_PX as i32 - BOX_LEFT_MARGIN - BOX_RIGHT_MARGIN;
-            const BOX_HEIGHT: i32 = 60;
+            const BOX_HEIGHT: i32 = 50;
 
-            const RUN_TYPE_Y: i32 = 66;
+            const RUN_TYPE_Y: i32 = 64;
             // const DIFFICULTY_Y: i32 = 33;
             // const CHARACTER_Y: i32 = 46;
-            const START_Y: i32 = 130;
+            const ASSISTS_Y: i32 = 115;
+            const ASSISTS_HEIGHT: i32 = 11;
+            const MOTION_Y: i32 = 126;
+            const CAMERA_Y: i32 = 137;
+            const START_Y: i32 = 146;
             const START_X: i32 = 48;
 
             // const START_WIDTH: i32 = 60;
@@ -1192,39 +1264,51 @@ fn update() {
 
             match select_setup.current_selection {
                 SelectMenuFocuses::RunType => {
-                    if btns_pressed_this_frame[0] & (BUTTON_RIGHT | BUTTON_LEFT) != 0 {
-                        game_state.settings.run_type = match game_state.settings.run_type {
-                            RunType::Casual => RunType::TimedMode,
-                            game::game_state::RunType::TimedMode => game::game_state::RunType::Speedrun(0),
-                            game::game_state::RunType::Speedrun(_) => game::game_state::RunType::Chaos,
-                            RunType::Chaos => RunType::Casual
-                        }   
-                    }
                     // draw box around run type
                     draw_selected_box((BOX_LEFT_MARGIN, RUN_TYPE_Y, BOX_WIDTH, BOX_HEIGHT), 1, 0x0004);
                     // layertext("Run Type", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST);
 
                     if game_state.song_timer % 30 >= 15 {
                         unsafe {*DRAW_COLORS = 0x0004}
-                        text(&[b'\x85'], 132, 72);
-                        text(&[b'\x80'], 45, 136);
+                        text(&[b'\x85'], 132, RUN_TYPE_Y + 6);
+                        text(&[b'\x80'], 45, START_Y + 6);
                     }
 
-                    if btns_pressed_this_frame[0] & (BUTTON_2) != 0 {
-                        if let game::game_state::RunType::Speedrun(n) = game_state.settings.run_type {
-                            game_state.settings.run_type = game::game_state::RunType::Speedrun(n + 1);
-                        }
+                    layertext("Start!", START_X + SETTING_GROUP_INLAY_DIST + 3, START_Y + SETTING_GROUP_INLAY_DIST + 1);
+                },
+                SelectMenuFocuses::Assists => {
+                    // draw box around the assists
+                    draw_selected_box((BOX_LEFT_MARGIN, ASSISTS_Y, BOX_WIDTH, ASSISTS_HEIGHT), 1, 0x0004);
+
+                    if game_state.song_timer % 30 >= 15 {
+                        unsafe {*DRAW_COLORS = 0x0004}
+                        text(&[b'\x85'], 132, ASSISTS_Y + 2);
+                        text(&[b'\x80'], 45, START_Y + 6);
+                    }
+
+                    layertext("Start!", START_X + SETTING_GROUP_INLAY_DIST + 3, START_Y + SETTING_GROUP_INLAY_DIST + 1);
+                },
+                SelectMenuFocuses::Motion => {
+                    draw_selected_box((BOX_LEFT_MARGIN, MOTION_Y, BOX_WIDTH, ASSISTS_HEIGHT), 1, 0x0004);
+
+                    if game_state.song_timer % 30 >= 15 {
+                        unsafe {*DRAW_COLORS = 0x0004}
+                        text(&[b'\x85'], 132, MOTION_Y + 2);
+                        text(&[b'\x80'], 45, START_Y + 6);
                     }
 
                     layertext("Start!", START_X + SETTING_GROUP_INLAY_DIST + 3, START_Y + SETTING_GROUP_INLAY_DIST + 1);
+                },
+                SelectMenuFocuses::Camera => {
+                    draw_selected_box((BOX_LEFT_MARGIN, CAMERA_Y, BOX_WIDTH, ASSISTS_HEIGHT), 1, 0x0004);
 
-                    if btns_pressed_this_frame[0] & BUTTON_1 != 0 {
-                        game_state.game_mode = GameMode::NormalPlay(NormalPlayModes::MainGameplay);
-                        if let game::game_state::RunType::Speedrun(n) = game_state.settings.run_type {
-                            game_state.rng = GameRng::FixedSeed(Rng::new_from_seed(n), Rng::new_from_seed(n));
-                        }
-                        game_state.regenerate_map();
+                    if game_state.song_timer % 30 >= 15 {
+                        unsafe {*DRAW_COLORS = 0x0004}
+                        text(&[b'\x85'], 132, CAMERA_Y + 2);
+                        text(&[b'\x80'], 45, START_Y + 6);
                     }
+
+                    layertext("Start!", START_X + SETTING_GROUP_INLAY_DIST + 3, START_Y + SETTING_GROUP_INLAY_DIST + 1);
                 },
                 // SelectMenuFocuses::Difficulty => {
                 //     // draw box around difficulty
@@ -1272,6 +1356,43 @@ fn update() {
                         text([b'\x81'], BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
                     }
                 },
+                RunType::Versus(n) => {
+                    layertext("Versus Mode", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 20, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST);
+                    layertext("Race for cats,", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 15);
+                    layertext("pounce to steal", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 25);
+                    layertext(&format![" best of: {}", n], BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 1, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
+                    if game_state.song_timer % 30 >= 15 {
+                        unsafe {*DRAW_COLORS = 0x0004}
+
+                        text([b'\x81'], BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
+                    }
+                },
+                RunType::Daily => {
+                    layertext("Daily Mode", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 20, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST);
+                    layertext("Same maps for", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 15);
+                    layertext("all. One try!", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 25);
+                    let day_text = match game_state.save.can_play_daily() {
+                        true => format![" day: #{}", game_state.save.daily_day],
+                        false => format![" #{}: played", game_state.save.daily_day],
+                    };
+                    layertext(&day_text, BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 1, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
+                    if game_state.song_timer % 30 >= 15 {
+                        unsafe {*DRAW_COLORS = 0x0004}
+
+                        text([b'\x81'], BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
+                    }
+                },
+                RunType::Endless => {
+                    layertext("Endless Mode", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 16, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST);
+                    layertext("No last level,", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 15);
+                    layertext("only harder!", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 25);
+                    let best = game_state.save.endless_leaderboard[0];
+                    let best_text = match best.level {
+                        0 => " best: none".to_string(),
+                        level => format![" best: W{}-L{}", (level as usize - 1) / LEVELS_PER_MOOD + 1, (level as usize - 1) % LEVELS_PER_MOOD + 1],
+                    };
+                    layertext(&best_text, BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 1, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 35);
+                },
                 RunType::Chaos => {
                     layertext("??? mode", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST + 20, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST);
                     layertext("For the", BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, RUN_TYPE_Y + SETTING_GROUP_INLAY_DIST + 15);
@@ -1282,6 +1403,21 @@ fn update() {
             }
             
 
+            layertext(match game_state.settings.kitty_arrows {
+                KittyArrows::Auto => "Arrows: auto",
+                KittyArrows::On => "Arrows: on",
+                KittyArrows::Off => "Arrows: off",
+            }, BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, ASSISTS_Y + 2);
+            layertext(match game_state.settings.reduced_motion {
+                false => "Motion: full",
+                true => "Motion: reduced",
+            }, BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, MOTION_Y + 2);
+            layertext(match game_state.settings.camera_style {
+                CameraStyles::Smart => "Camera: smart",
+                CameraStyles::Room => "Camera: room",
+                CameraStyles::Classic => "Camera: classic",
+            }, BOX_LEFT_MARGIN + SETTING_GROUP_INLAY_DIST, CAMERA_Y + 2);
+
             // Draw box around selection
             // draw_modal_bg(&AbsoluteBoundingBox{x: selected_box_dims.0 as f32, y: selected_box_dims.1 as f32, width: selected_box_dims.2 as f32, height: selected_box_dims.3 as f32}, 1);
 
@@ -7,7 +7,7 @@ pub const KITTY_SPRITESHEET_PAGE_WIDTH: u8 = 32;
 pub const KITTY_SPRITESHEET_STRIDE: u8 = KITTY_SPRITESHEET_PAGE_WIDTH * 6;
 
 #[rustfmt::skip]
-pub const KITTY_SPRITESHEET_PALETTES: [[u32; 4]; 9] = [
+pub const KITTY_SPRITESHEET_PALETTES: [[u32; 4]; 10] = [
     //   bg,      fg,     enemy,     cat
     [0x70efc8, 0xf0ff8c, 0xff6633, 0xfcffec],
     [0x4B0082, 0xFF69B4, 0xFFA500, 0x90EE90],
@@ -17,7 +17,8 @@ pub const KITTY_SPRITESHEET_PALETTES: [[u32; 4]; 9] = [
     [0x650515, 0x4af1da, 0xf97c32, 0x93fc23],
     [0x3a4c52, 0xffdab9, 0xff69b4, 0x00ffaa],
     [0xade0d1, 0xf0ff8c, 0xff6633, 0xfcffec],
-    [0x000000, 0xFF4500, 0xFFD700, 0x00CED1]
+    [0x000000, 0xFF4500, 0xFFD700, 0x00CED1],
+    [0x0a2a4a, 0x7fd4ff, 0xff7a59, 0xf6f6a0]
 ];
 
 // KITTY_SPRITESHEET
@@ -72,11 +73,31 @@ pub enum PresetSprites {
     Cloud,
     Clock,
     CatHead,
+    FishPickup,
+    KeyPickup,
+    CardPackPickup,
+    Lock,
+    DashCard,
+    DoubleJumpCard,
+    MagnetCard,
+    RevealCard,
+    FreezeCard,
+    ShrinkCard,
+    WarpPad,
 }
 
 static mut SPRITES: Option<Vec<Sprite>> = None;
 
+// tests run on many threads, but the sprites can only be set up once.
+#[cfg(test)]
+static INIT_SPRITES_FOR_TESTS: std::sync::Once = std::sync::Once::new();
+
 impl Sprite {
+    #[cfg(test)]
+    pub fn init_all_sprites_for_tests() {
+        INIT_SPRITES_FOR_TESTS.call_once(Sprite::init_all_sprites);
+    }
+
     pub fn init_all_sprites() {
         unsafe {
             match &mut SPRITES {
@@ -105,6 +126,7 @@ impl Sprite {
         //  ---------- TILESET ------------
 
         // 0: main kitty
+        // (6 and 7 are hanging from a ledge and pulling up onto it; the lil ones reuse their wall frames)
         the_sprites.push(Sprite::from_page_x_y_w_h(vec![
             [0, 16, 55, 14, 9],
             [1, 16, 55, 13, 9],
@@ -112,6 +134,8 @@ impl Sprite {
             [3, 16, 50, 11, 14],
             [4, 16, 52, 6, 12],
             [5, 16, 54, 12, 10],
+            [4, 0, 12, 6, 12],
+            [4, 8, 12, 8, 10],
         ]));
 
         // 1: lil kitty 1
@@ -122,6 +146,8 @@ impl Sprite {
             [3, 0, 38, 9, 10],
             [4, 0, 39, 6, 9],
             [5, 0, 41, 7, 7],
+            [4, 0, 39, 6, 9],
+            [3, 0, 38, 9, 10],
         ]));
 
         // 2: lil kitty 2
@@ -132,6 +158,8 @@ impl Sprite {
             [3, 0, 48, 10, 8],
             [4, 0, 48, 5, 8],
             [5, 0, 50, 8, 7],
+            [4, 0, 48, 5, 8],
+            [3, 0, 48, 10, 8],
         ]));
 
         // 3: lil kitty 3
@@ -142,6 +170,8 @@ impl Sprite {
             [3, 0, 57, 10, 7],
             [4, 0, 56, 5, 8],
             [5, 0, 57, 8, 7],
+            [4, 0, 56, 5, 8],
+            [3, 0, 57, 10, 7],
         ]));
 
         // 4: lil kitty 4
@@ -152,6 +182,8 @@ impl Sprite {
             [3, 16, 40, 10, 8],
             [4, 16, 40, 5, 8],
             [5, 16, 41, 8, 7],
+            [4, 16, 40, 5, 8],
+            [3, 16, 40, 10, 8],
         ]));
 
         // 5: pig
@@ -162,6 +194,8 @@ impl Sprite {
             [1, 16, 35, 8, 5],
             [0, 16, 35, 8, 5],
             [1, 16, 35, 8, 5],
+            [0, 16, 35, 8, 5],
+            [1, 16, 35, 8, 5],
         ]));
 
         // 6: lizard
@@ -172,6 +206,8 @@ impl Sprite {
             [1, 16, 24, 10, 8],
             [0, 16, 23, 10, 9],
             [1, 16, 24, 10, 8],
+            [0, 16, 23, 10, 9],
+            [1, 16, 24, 10, 8],
         ]));
 
         // 7: bird
@@ -182,6 +218,8 @@ impl Sprite {
             [1, 16, 8, 10, 8],
             [2, 16, 10, 10, 6],
             [1, 16, 8, 10, 8],
+            [2, 16, 10, 10, 6],
+            [1, 16, 8, 10, 8],
         ]));
 
         // -------- tiles ---------
@@ -277,6 +315,43 @@ impl Sprite {
 
         // 35: cat head
         the_sprites.push(Sprite::from_page_x_y_w_h(vec![[3, 3, 3, 6, 6]]));
+
+        // ------- PICKUPS ----------
+
+        // 36: fish
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[4, 0, 0, 8, 5]]));
+
+        // 37: key
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[4, 9, 0, 8, 4]]));
+
+        // 38: card pack
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[4, 18, 0, 8, 8]]));
+
+        // 39: lock
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[4, 27, 0, 5, 5]]));
+
+        // ------- TIMED EFFECT CARDS ----------
+
+        // 40: dash card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 0, 0, 12, 12]]));
+
+        // 41: double jump card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 12, 0, 12, 12]]));
+
+        // 42: magnet card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 0, 12, 12, 12]]));
+
+        // 43: reveal card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 12, 12, 12, 12]]));
+
+        // 44: freeze timer card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 0, 24, 12, 12]]));
+
+        // 45: shrink card
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[5, 12, 24, 12, 12]]));
+
+        // 46: warp pad
+        the_sprites.push(Sprite::from_page_x_y_w_h(vec![[4, 0, 8, 8, 3]]));
     }
 
     pub fn get_spritesheet() -> &'static Vec<Sprite> {
@@ -356,6 +431,17 @@ impl Sprite {
             PresetSprites::Cloud => &sprites_vec[33],
             PresetSprites::Clock => &sprites_vec[34],
             PresetSprites::CatHead => &sprites_vec[35],
+            PresetSprites::FishPickup => &sprites_vec[36],
+            PresetSprites::KeyPickup => &sprites_vec[37],
+            PresetSprites::CardPackPickup => &sprites_vec[38],
+            PresetSprites::Lock => &sprites_vec[39],
+            PresetSprites::DashCard => &sprites_vec[40],
+            PresetSprites::DoubleJumpCard => &sprites_vec[41],
+            PresetSprites::MagnetCard => &sprites_vec[42],
+            PresetSprites::RevealCard => &sprites_vec[43],
+            PresetSprites::FreezeCard => &sprites_vec[44],
+            PresetSprites::ShrinkCard => &sprites_vec[45],
+            PresetSprites::WarpPad => &sprites_vec[46],
         }
     }
 }